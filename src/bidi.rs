@@ -0,0 +1,36 @@
+//! Bidirectional text. A description in a right-to-left language such as Arabic or Hebrew
+//! embeds left-to-right runs, times such as "09:00 AM", numbers and years, which a renderer
+//! may reorder with the text around them. Such runs are wrapped in isolation marks so they
+//! always read in their own order.
+//!
+//! A locale written right to left says so with `direction: rtl`.
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::inflection::translate_optional;
+
+const LEFT_TO_RIGHT_ISOLATE: char = '\u{2066}';
+const POP_DIRECTIONAL_ISOLATE: char = '\u{2069}';
+
+/// Whether the current locale is written right to left.
+pub(crate) fn is_right_to_left() -> bool {
+    translate_optional("direction").is_some_and(|direction| direction == "rtl")
+}
+
+/// A description with its left-to-right runs isolated when the current locale is written
+/// right to left, unchanged otherwise.
+pub(crate) fn isolate(description: String) -> String {
+    lazy_static! {
+        static ref LEFT_TO_RIGHT_RUN: Regex =
+            Regex::new(r"[0-9A-Za-z](?:[0-9A-Za-z:. ]*[0-9A-Za-z])?").unwrap();
+    }
+    if !is_right_to_left() {
+        return description;
+    }
+    LEFT_TO_RIGHT_RUN
+        .replace_all(&description, |run: &regex::Captures| {
+            format!("{}{}{}", LEFT_TO_RIGHT_ISOLATE, &run[0], POP_DIRECTIONAL_ISOLATE)
+        })
+        .into_owned()
+}
@@ -1,16 +1,14 @@
 use std::collections::HashMap;
-use lazy_static::lazy_static;
-use crate::cronparser::Options;
-use crate::{format_minutes, string_utils};
+use crate::cronparser::{Options, Verbosity};
+use crate::inflection::{self, Position};
+use crate::{format_minutes, plural, string_utils};
 use crate::date_time_utils;
-use substring::Substring;
 
 
 use strfmt::{strfmt, strfmt_builder};
 use string_builder::Builder;
-use crate::date_time_utils::{format_time, MONTHS_ARR};
-
-i18n!("locales");
+use crate::date_time_utils::{format_compact_time, format_time, DAY_OF_WEEK_KEYS, MONTHS_ARR};
+use crate::schedule::{self, DayOfWeekItem};
 
 const SPECIAL_CHARACTERS_MINUS_STAR: [char; 3] = ['/', '-', ','];
 
@@ -22,7 +20,7 @@ pub trait DescriptionBuilder<'a> {
             all_description
         } else if string_utils::not_contains_any(expression, &SPECIAL_CHARACTERS_MINUS_STAR) {
             let gdf = self.get_description_format(expression);
-            let sid = self.get_single_item_description(expression);
+            let sid = self.get_item_description_at(expression, Position::Only);
             let mut vars = HashMap::new();
             vars.insert("0".to_string(), sid);
             strfmt(&gdf, &vars).unwrap()
@@ -36,57 +34,50 @@ pub trait DescriptionBuilder<'a> {
             if segments[0].contains("-") {
                 let between_segments_of_interval = segments[0].to_string();
                 let between_segments = between_segments_of_interval.split("-").collect::<Vec<_>>();
-                let gbdf = self.get_between_description_format(false);
-                let sid0 = self.get_single_item_description(&between_segments[0].to_string());
-                let sid1 = self.get_single_item_description(&between_segments[1].to_string());
+                let gbdf = self.get_between_description_format(true);
+                let sid0 = self.get_item_description_at(&between_segments[0].to_string(), Position::From);
+                let sid1 = self.get_item_description_at(&last_in_range(between_segments[0], between_segments[1], segments[1]), Position::To);
                 let mut vars = HashMap::new();
                 vars.insert("0".to_string(), sid0);
                 vars.insert("1".to_string(), sid1);
-                format!("{}, {}", tmpstr, strfmt(&gbdf, &vars).unwrap())
+                format!("{}{}{}", tmpstr, separator(), strfmt(&gbdf, &vars).unwrap())
+            } else if segments[0] != "*" {
+                let mut vars = HashMap::new();
+                vars.insert("0".to_string(), self.get_item_description_at(&segments[0].to_string(), Position::Starting));
+                let starting = strfmt(&self.get_starting_description_format(&segments[0].to_string()), &vars).unwrap();
+                let mut vars = HashMap::new();
+                vars.insert("0".to_string(), starting);
+                format!("{}{}{}", tmpstr, separator(), strfmt(&t!("messages.starting_x"), &vars).unwrap())
             } else {
                 // println!("gidf: {}, gsid: {}", gidf, gsid2);
                 tmpstr
             }
         } else if expression.contains(",") {
             let segments = expression.split(",").collect::<Vec<_>>();
-            let mut description_content = Builder::default();
-            for i in 0..segments.len() {
-                if i > 0 && segments.len() > 2 {
-                    if i < segments.len() - 1 {
-                        description_content.append(", ");
-                    }
-                }
-                if i > 0 && segments.len() > 1 && (i == segments.len() - 1 || segments.len() == 2) {
-                    if self.need_space_between_words() {
-                        description_content.append(" ");
-                    }
-                    description_content.append(t!("and"));
-                    if self.need_space_between_words() {
-                        description_content.append(" ");
-                    }
-                }
-                if segments[i].contains("-") {
-                    let between_segments = segments[i].split("-").collect::<Vec<_>>();
-                    let gbdf = self.get_between_description_format(true);
-                    let sid0 = self.get_single_item_description(&between_segments[0].to_string());
-                    let sid1 = self.get_single_item_description(&between_segments[1].to_string());
+            let mut items = Vec::new();
+            for segment in &segments {
+                if segment.contains("-") {
+                    let between_segments = segment.split("-").collect::<Vec<_>>();
+                    let gbdf = self.get_range_format();
+                    let sid0 = self.get_item_description_at(&between_segments[0].to_string(), Position::From);
+                    let sid1 = self.get_item_description_at(&between_segments[1].to_string(), Position::To);
                     let mut vars = HashMap::new();
                     vars.insert("0".to_string(), sid0);
                     vars.insert("1".to_string(), sid1);
-                    description_content.append(strfmt(&gbdf, &vars).unwrap());
+                    items.push(strfmt(&gbdf, &vars).unwrap());
                 } else {
-                    description_content.append(self.get_single_item_description(&segments[i].to_string()));
+                    items.push(self.get_item_description_at(&segment.to_string(), Position::Only));
                 }
             }
             let mut vars = HashMap::new();
-            vars.insert("0".to_string(), description_content.string().unwrap());
+            vars.insert("0".to_string(), join_list(&items, self.need_space_between_words()));
             strfmt(&self.get_description_format(expression), &vars).unwrap()
         } else if expression.contains("-") {
             // println!("in get_segment_description, expression:{}, {}:{}", expression, file!(), line!());
             let segments = expression.split("-").collect::<Vec<_>>();
             let gbdf = self.get_between_description_format(false);
-            let sid0 = self.get_single_item_description(&segments[0].to_string());
-            let sid1 = self.get_single_item_description(&segments[1].to_string());
+            let sid0 = self.get_item_description_at(&segments[0].to_string(), Position::From);
+            let sid1 = self.get_item_description_at(&segments[1].to_string(), Position::To);
             let mut vars = HashMap::new();
             vars.insert("0".to_string(), sid0);
             vars.insert("1".to_string(), sid1);
@@ -102,97 +93,312 @@ pub trait DescriptionBuilder<'a> {
     fn get_interval_description_format(&self, expression: &String) -> String;
     fn get_single_item_description(&self, expression: &String) -> String;
     fn get_description_format(&self, expression: &String) -> String;
-    fn need_space_between_words(&self) -> bool;
 
-    fn get_space_opt(options: &Options) -> String {
-        if options.need_space_between_words {
-            " ".to_string()
-        } else {
-            "".to_string()
-        }
+    // Description of a single item where it stands, for languages that inflect names by their
+    // position such as "only on Monday" and "from Monday".
+    fn get_item_description_at(&self, expression: &String, _position: Position) -> String {
+        self.get_single_item_description(expression)
     }
 
-    fn get_space(&self) -> String;
+    // Format of a range in a list, whose description format already says what the values are,
+    // as "5 through 10" in "on day 1 and 5 through 10 of the month".
+    fn get_range_format(&self) -> String {
+        self.get_between_description_format(true)
+    }
 
-    fn plural_num(num: i8, singular: &'a String, plural: &'a String) -> &'a String {
-        Self::plural(&num.to_string(), singular, plural)
+    // Format of the first value of a step that does not start at the lowest value, the 5 of
+    // `5/15`.
+    fn get_starting_description_format(&self, expression: &String) -> String {
+        without_separator(&self.get_description_format(expression))
     }
 
+    fn need_space_between_words(&self) -> bool;
 
-    fn plural(expression: &String, singular: &'a String, plural: &'a String) -> &'a String {
-        let parsed_expr = expression.parse::<i8>();
-        if parsed_expr.is_ok() && parsed_expr.unwrap() > 1 {
-            plural
-        } else if expression.contains(",") {
-            plural
-        } else {
-            singular
+    fn verbosity(&self) -> Verbosity;
+
+    /// Terse description for [`Verbosity::Compact`]: items, `a–b` ranges and lists of them,
+    /// such as "Mon–Fri". `None` for steps and the Quartz `L`, `W` and `#` forms.
+    fn get_compact_description(&self, expression: &String) -> Option<String> {
+        if expression == "*" || expression.is_empty() {
+            return Some(String::new());
         }
+        if expression.contains(['/', 'L', 'W', '#']) {
+            return None;
+        }
+        let items: Vec<String> = expression
+            .split(',')
+            .map(|item| match item.split_once('-') {
+                Some((from, to)) => format!(
+                    "{}–{}",
+                    self.get_compact_item_description(from),
+                    self.get_compact_item_description(to)
+                ),
+                None => self.get_compact_item_description(item),
+            })
+            .collect();
+        Some(items.join(&separator()))
+    }
+
+    fn get_compact_item_description(&self, expression: &str) -> String {
+        self.get_single_item_description(&expression.to_string())
     }
+}
 
-    fn min_plural(expression: &String) -> String {
-        lazy_static! {
-            static ref MINUTE: String = t!("minute");
-            static ref MINUTES: String = t!("minutes");
+// The last value a step actually reaches in a range, `9-17/5` ends at 14.
+fn last_in_range(start: &str, end: &str, step: &str) -> String {
+    match (start.parse::<u32>(), end.parse::<u32>(), step.parse::<u32>()) {
+        (Ok(start), Ok(end), Ok(step)) if start <= end && step > 0 => {
+            (start + (end - start) / step * step).to_string()
         }
-        Self::plural(expression, &MINUTE, &MINUTES).to_string()
+        _ => end.to_string(),
     }
 }
 
+/// Whether words are separated by spaces: as `need_space_between_words` asks, unless the
+/// locale writes without spaces between words, as Japanese and Chinese do.
+pub(crate) fn need_space(options: &Options) -> bool {
+    options.need_space_between_words && t!("space_between_words") != "false"
+}
+
+/// Separator of the parts of a description, ", " unless the locale has its own such as "、".
+pub(crate) fn separator() -> String {
+    inflection::translate_optional("separator").unwrap_or_else(|| ", ".to_string())
+}
+
+/// A part of a description without the separator it starts with, if any.
+pub(crate) fn without_separator(description: &str) -> String {
+    let separator = separator();
+    description.strip_prefix(separator.as_str()).unwrap_or(description).to_string()
+}
+
+/// A count of a unit such as "5 minutes".
+pub(crate) fn count(unit: &str, number: &str, options: &Options) -> String {
+    let space = if need_space(options) { " " } else { "" };
+    plural::count(unit, number, space)
+}
+
+// Format of a range of numbers in a list, "{0} through {1}" unless the locale has one that
+// reads after the preposition the list starts with, as the Polish "o 50–59 i 00–10 minuty".
+fn list_range_format() -> String {
+    inflection::translate_optional("messages.list_range_description_format")
+        .unwrap_or_else(|| t!("messages.between_description_format"))
+}
+
+// "every 5 minutes", the count in place of `{0}` of `messages.every_x` so that locales may
+// put words after it.
+fn every_x(unit: &str, step: &str, options: &Options) -> String {
+    let mut vars = HashMap::new();
+    vars.insert("0".to_string(), count(unit, step, options));
+    strfmt(&inflection::agree_message("messages.every_x", unit), &vars).unwrap()
+}
+
+// Joins list items as in "a, b and c".
+pub(crate) fn join_list(items: &[String], need_space_between_words: bool) -> String {
+    let list_separator = inflection::translate_optional("list_separator").unwrap_or_else(|| ", ".to_string());
+    let mut description_content = Builder::default();
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 && i < items.len() - 1 {
+            description_content.append(list_separator.as_str());
+        } else if let Some(last_separator) = inflection::translate_optional("last_list_separator").filter(|_| i > 0) {
+            // As " و" in Arabic, where "and" is written joined to the next word.
+            description_content.append(last_separator);
+        } else if i > 0 {
+            if need_space_between_words {
+                description_content.append(" ");
+            }
+            description_content.append(t!("and"));
+            if need_space_between_words {
+                description_content.append(" ");
+            }
+        }
+        description_content.append(item.as_str());
+    }
+    description_content.string().unwrap()
+}
+
 pub struct DayOfMonthDescriptionBuilder<'a> {
-    pub options: &'a Options,
+    pub options: &'a Options<'a>,
 }
 
 pub struct DayOfWeekDescriptionBuilder<'a> {
-    pub options: &'a Options,
+    pub options: &'a Options<'a>,
 }
 
 pub struct HoursDescriptionBuilder<'a> {
-    pub options: &'a Options,
+    pub options: &'a Options<'a>,
 }
 
 pub struct MinutesDescriptionBuilder<'a> {
-    pub options: &'a Options,
+    pub options: &'a Options<'a>,
 }
 
 pub struct MonthDescriptionBuilder<'a> {
-    pub options: &'a Options,
+    pub options: &'a Options<'a>,
 }
 
 pub struct SecondsDescriptionBuilder<'a> {
-    pub options: &'a Options,
+    pub options: &'a Options<'a>,
 }
 
 pub struct YearDescriptionBuilder<'a> {
-    pub options: &'a Options,
+    pub options: &'a Options<'a>,
 }
 
 impl DescriptionBuilder<'_> for DayOfMonthDescriptionBuilder<'_> {
     fn get_between_description_format(&self, omit_separator: bool) -> String {
-        let format = t!("messages.between_days_of_the_month");
+        let format = if self.options.ordinal_days {
+            t!("messages.between_the_x_and_y_of_the_month")
+        } else {
+            t!("messages.between_days_of_the_month")
+        };
         if omit_separator {
             format
         } else {
-            format!(", {}", format)
+            separator() + &format
         }
     }
 
+    fn get_range_format(&self) -> String {
+        list_range_format()
+    }
+
     fn get_interval_description_format(self: &Self, expression: &String) -> String {
-        ", ".to_string() + &t!("every_x") + &self.get_space() + &Self::plural(expression, &t!("day"), &t!("days"))
+        if expression == "1" && self.verbosity() != Verbosity::Verbose {
+            return separator() + &t!("messages.every_day");
+        }
+        // The step is a count of days, never an ordinal.
+        separator() + &every_x("day", expression, self.options)
     }
 
-    fn get_single_item_description(&self, expression: &String) -> String { expression.to_string() }
+    fn get_single_item_description(&self, expression: &String) -> String {
+        match expression.parse::<u32>() {
+            Ok(day) if self.options.ordinal_days => plural::ordinal(day),
+            _ => expression.to_string(),
+        }
+    }
 
     fn get_description_format(&self, _: &String) -> String {
-        ", ".to_string() + &t!("messages.on_day_of_month")
+        if self.options.ordinal_days {
+            separator() + &t!("messages.on_the_x_of_the_month")
+        } else {
+            separator() + &t!("messages.on_day_of_month")
+        }
     }
 
     fn need_space_between_words(&self) -> bool {
-        self.options.need_space_between_words
+        need_space(self.options)
+    }
+
+    fn verbosity(&self) -> Verbosity {
+        self.options.verbosity
+    }
+
+}
+
+const ORDINAL_KEYS: [&str; 5] = ["first", "second", "third", "fourth", "fifth"];
+
+// Ordinal of an occurrence of a weekday in a month, `#` being validated to be 1 to 5.
+fn ordinal(nth: u32, day: u32, options: &Options) -> String {
+    if options.ordinal_days {
+        // A numeral ordinal, with the inflection the locale gives it before this day, if any,
+        // for this number such as the Spanish "1.er" or for any.
+        inflection::agree_optional(&format!("ordinal_{}", nth), day_key(day), Position::Nth)
+            .or_else(|| inflection::agree_optional("ordinal", day_key(day), Position::Nth))
+            .map(|ordinal| ordinal.replace("{0}", &nth.to_string()))
+            .unwrap_or_else(|| plural::ordinal(nth))
+    } else {
+        inflection::agree(ORDINAL_KEYS[nth as usize - 1], day_key(day), Position::Nth)
+    }
+}
+
+// Translation key of a day of the week, 0 or 7 being Sunday.
+fn day_key(day: u32) -> &'static str {
+    DAY_OF_WEEK_KEYS[day as usize % 7]
+}
+
+impl DayOfWeekDescriptionBuilder<'_> {
+    /// Describes a list with `#` or `L` items, such as `MON#1,FRI#3`, item by item. `None`
+    /// when the expression is not such a list.
+    pub fn get_list_with_occurrences_description(&self, expression: &str) -> Option<String> {
+        let segments: Vec<&str> = expression.split(',').collect();
+        if segments.len() < 2 || !expression.contains(['#', 'L']) {
+            return None;
+        }
+        let mut items = Vec::new();
+        for segment in segments {
+            if let Some((from, to)) = segment.split_once('-') {
+                let mut vars = HashMap::new();
+                vars.insert("0".to_string(), self.get_item_description_at(&from.to_string(), Position::From));
+                vars.insert("1".to_string(), self.get_item_description_at(&to.to_string(), Position::To));
+                items.push(strfmt(&self.get_between_description_format(true), &vars).unwrap());
+                continue;
+            }
+            let day = self.get_item_description_at(&segment.to_string(), Position::Only);
+            let item = match schedule::parse_day_of_week_item(segment, self.options.zero_based_day_of_week) {
+                Some(DayOfWeekItem::Nth(day_of_week, nth)) =>
+                    strfmt!(&t!("messages.the_nth_x"), nth => ordinal(nth, day_of_week, self.options), day_of_week => day).unwrap(),
+                Some(DayOfWeekItem::Last(day_of_week)) => {
+                    let mut vars = HashMap::new();
+                    vars.insert("0".to_string(), day);
+                    vars.insert("last".to_string(), inflection::agree("last", day_key(day_of_week), Position::Last));
+                    strfmt(&t!("messages.the_last_x"), &vars).unwrap()
+                }
+                _ => day,
+            };
+            items.push(item);
+        }
+        let mut vars = HashMap::new();
+        vars.insert("0".to_string(), join_list(&items, self.need_space_between_words()));
+        Some(separator() + &strfmt(&t!("messages.on_x_of_the_month"), &vars).unwrap())
+    }
+
+    /// Compact days of the week: "Weekdays" and "Weekends" where they fit, abbreviated
+    /// names otherwise.
+    pub fn get_compact_days_description(&self, expression: &str) -> Option<String> {
+        let mut days = Vec::new();
+        for item in expression.split(',') {
+            if item.contains(['/', 'L', '#']) || item == "*" {
+                return self.get_compact_description(&expression.to_string());
+            }
+            let (from, to) = item.split_once('-').unwrap_or((item, item));
+            let day = |d: &str| schedule::parse_day_of_week_item(d, self.options.zero_based_day_of_week);
+            match (day(from), day(to)) {
+                (Some(DayOfWeekItem::Day(from)), Some(DayOfWeekItem::Day(to))) if from <= to => {
+                    days.extend(from..=to)
+                }
+                _ => return self.get_compact_description(&expression.to_string()),
+            }
+        }
+        days.sort_unstable();
+        days.dedup();
+        match days.as_slice() {
+            [1, 2, 3, 4, 5] => Some(t!("compact.weekdays")),
+            [0, 6] => Some(t!("compact.weekends")),
+            _ => self.get_compact_description(&expression.to_string()),
+        }
     }
 
-    fn get_space(self: &Self) -> String {
-        Self::get_space_opt(&self.options)
+    /// Describes the days of the week as an alternative to the days of the month, such as
+    /// "or on Monday", for dialects that fire on a day matching either field.
+    pub fn get_alternative_description(&self, expression: &str) -> String {
+        let plain_days = expression.split(',').all(|s| !s.contains(['-', '/', '*', 'L', '#']));
+        let description = if plain_days {
+            let days: Vec<String> = expression
+                .split(',')
+                .map(|s| self.get_item_description_at(&s.to_string(), Position::On))
+                .collect();
+            let mut vars = HashMap::new();
+            vars.insert("0".to_string(), join_list(&days, self.need_space_between_words()));
+            strfmt(&t!("messages.on_x"), &vars).unwrap()
+        } else {
+            let description = self
+                .get_list_with_occurrences_description(expression)
+                .unwrap_or_else(|| self.get_segment_description(&expression.to_string(), String::new()));
+            without_separator(&description)
+        };
+        let mut vars = HashMap::new();
+        vars.insert("0".to_string(), description);
+        strfmt(&t!("messages.or_x"), &vars).unwrap()
     }
 }
 
@@ -203,76 +409,78 @@ impl DescriptionBuilder<'_> for DayOfWeekDescriptionBuilder<'_> {
         if omit_separator {
             format
         } else {
-            format!(", {}", format)
+            separator() + &format
         }
     }
 
     fn get_interval_description_format(&self, expression: &String) -> String {
-        String::from(", ") + &t!("messages.interval_description_format", 0 = expression)
+        let format = t!("messages.interval_description_format").replace("{1}", &plural::unit("day", expression));
+        let mut vars = HashMap::new();
+        vars.insert("0".to_string(), expression.to_string());
+        separator() + &strfmt(&format, &vars).unwrap()
     }
 
     fn get_single_item_description(&self, expression: &String) -> String {
-        let exp = match expression.find("#") {
-            Some(ind) =>
-                expression.substring(0, ind).to_string(),
-            None =>
-                match expression.find("L") {
-                    Some(_) =>
-                        expression.replace("L", ""),
-                    None => expression.to_string()
-                }
-        };
-
-        if string_utils::is_numeric(&exp) {
-            let mut day_of_week_num = exp.parse::<u8>().unwrap();
-            let is_invalid_day_of_week_for_setting = !self.options.zero_based_day_of_week
-                && day_of_week_num <= 1;
-            if is_invalid_day_of_week_for_setting ||
-                (self.options.zero_based_day_of_week && day_of_week_num == 0) {
-                return date_time_utils::get_day_of_week_name(7);
-            } else if !self.options.zero_based_day_of_week {
-                day_of_week_num -= 1;
+        match schedule::parse_day_of_week_item(expression, self.options.zero_based_day_of_week) {
+            // Sunday is 7 in `get_day_of_week_name`.
+            Some(DayOfWeekItem::Day(0) | DayOfWeekItem::Last(0) | DayOfWeekItem::Nth(0, _)) =>
+                date_time_utils::get_day_of_week_name(7),
+            Some(DayOfWeekItem::Day(day) | DayOfWeekItem::Last(day) | DayOfWeekItem::Nth(day, _)) =>
+                date_time_utils::get_day_of_week_name(day as usize),
+            None => {
+                // Get localized day of week name, or the item itself when it names none.
+                let lowered = expression.to_lowercase();
+                let capitalized = lowered[0..1].to_uppercase() + &lowered[1..];
+                inflection::translate_optional(&capitalized).unwrap_or_else(|| expression.to_string())
             }
-            date_time_utils::get_day_of_week_name(day_of_week_num as usize)
-        } else {
-            // Get localized day of week name
-            let lowered = exp.to_lowercase();
-            let capitalized = lowered[0..1].to_uppercase() + &lowered[1..];
-            t!(&capitalized)
+        }
+    }
+
+    fn get_item_description_at(&self, expression: &String, position: Position) -> String {
+        let plain = self.get_single_item_description(expression);
+        match schedule::parse_day_of_week_item(expression, self.options.zero_based_day_of_week) {
+            Some(DayOfWeekItem::Day(day)) => inflection::inflect("day_of_week", day_key(day), position, plain),
+            Some(DayOfWeekItem::Nth(day, _)) => inflection::inflect("day_of_week", day_key(day), Position::Nth, plain),
+            Some(DayOfWeekItem::Last(day)) => inflection::inflect("day_of_week", day_key(day), Position::Last, plain),
+            None => plain,
+        }
+    }
+
+    fn get_compact_item_description(&self, expression: &str) -> String {
+        match schedule::parse_day_of_week_item(expression, self.options.zero_based_day_of_week) {
+            Some(DayOfWeekItem::Day(day)) => date_time_utils::get_day_of_week_abbreviation(day as usize),
+            _ => self.get_single_item_description(&expression.to_string()),
         }
     }
 
     fn get_description_format(&self, expression: &String) -> String {
-        let format = if expression.contains("#") {
-            let hash_ind = expression.find('#').unwrap() + 1;
-            let day_of_week_of_month_number = &expression[hash_ind..];
-            let day_of_week_month_description = match day_of_week_of_month_number {
-                "1" => t!("first"),
-                "2" => t!("second"),
-                "3" => t!("third"),
-                "4" => t!("fourth"),
-                "5" => t!("fifth"),
-                _ => "".to_string()
-            };
-            let i18_str = t!("messages.on_the_day_of_the_month");
-            let msg = strfmt!(&i18_str, nth => day_of_week_month_description,
-                           day_of_week => "{0}");
-            String::from(", ") + msg.unwrap().as_str()
-        } else if expression.contains("L") {
-            format!("{} {}", ",", t!("messages.on_the_last_of_the_month"))
-        } else {
-            format!("{} {}", ",", t!("messages.only_on"))
-        };
-        format
+        match schedule::parse_day_of_week_item(expression, self.options.zero_based_day_of_week) {
+            Some(DayOfWeekItem::Nth(day, nth)) => {
+                let i18_str = t!("messages.on_the_day_of_the_month");
+                let msg = strfmt!(&i18_str, nth => ordinal(nth, day, self.options),
+                               day_of_week => "{0}");
+                separator() + msg.unwrap().as_str()
+            }
+            Some(DayOfWeekItem::Last(day)) => {
+                let last = inflection::agree("last", day_key(day), Position::Last);
+                separator() + &t!("messages.on_the_last_of_the_month").replace("{last}", &last)
+            }
+            _ => separator() + &t!("messages.only_on"),
+        }
+    }
+
+    fn get_starting_description_format(&self, _: &String) -> String {
+        t!("messages.on_x")
     }
 
     fn need_space_between_words(self: &Self) -> bool {
-        self.options.need_space_between_words
+        need_space(self.options)
     }
 
-    fn get_space(self: &Self) -> String {
-        Self::get_space_opt(&self.options)
+    fn verbosity(&self) -> Verbosity {
+        self.options.verbosity
     }
+
 }
 
 impl DescriptionBuilder<'_> for HoursDescriptionBuilder<'_> {
@@ -281,30 +489,32 @@ impl DescriptionBuilder<'_> for HoursDescriptionBuilder<'_> {
     }
 
     fn get_interval_description_format(&self, expression: &String) -> String {
-        //  return MessageFormat.format(I18nMessages.get("every_x")+ getSpace(options) +
-        //                 plural(expression, I18nMessages.get("hour"), I18nMessages.get("hours")), expression
-
-        let gdf = t!("messages.every_x") + &self.get_space() + &Self::plural(expression, &t!("hour"), &t!("hours"));
-        let mut vars = HashMap::new();
-        vars.insert("0".to_string(), expression.to_string());
-        strfmt(&gdf, &vars).unwrap()
+        if expression == "1" && self.verbosity() != Verbosity::Verbose {
+            return t!("messages.every_hour");
+        }
+        every_x("hour", expression, self.options)
     }
 
     fn get_single_item_description(&self, expression: &String) -> String {
         format_time(expression, &String::from("0"), &self.options)
     }
 
+    fn get_compact_item_description(&self, expression: &str) -> String {
+        format_compact_time(&expression.to_string(), &String::from("0"), &String::new(), self.options)
+    }
+
     fn get_description_format(&self, _: &String) -> String {
         t!("messages.at_x")
     }
 
     fn need_space_between_words(&self) -> bool {
-        self.options.need_space_between_words
+        need_space(self.options)
     }
 
-    fn get_space(&self) -> String {
-        Self::get_space_opt(&self.options)
+    fn verbosity(&self) -> Verbosity {
+        self.options.verbosity
     }
+
 }
 
 impl DescriptionBuilder<'_> for MinutesDescriptionBuilder<'_> {
@@ -312,34 +522,49 @@ impl DescriptionBuilder<'_> for MinutesDescriptionBuilder<'_> {
         t!("messages.minutes_through_past_the_hour")
     }
 
+    fn get_range_format(&self) -> String {
+        list_range_format()
+    }
+
     fn get_interval_description_format(&self, expression: &String) -> String {
-        // return MessageFormat.format(I18nMessages.get("every_x") + getSpace(options) + minPlural(expression), expression);
-        let gdf = t!("messages.every_x") + &self.get_space() + &Self::min_plural(expression);
-        let mut vars = HashMap::new();
-        vars.insert("0".to_string(), expression.to_string());
-        strfmt(&gdf, &vars).unwrap()
+        if expression == "1" && self.verbosity() != Verbosity::Verbose {
+            return t!("messages.every_minute");
+        }
+        every_x("minute", expression, self.options)
     }
 
     fn get_single_item_description(&self, expression: &String) -> String {
         format_minutes(expression)
     }
 
+    // The start of a step reads as a count, "starting at 5 minutes past the hour".
+    fn get_item_description_at(&self, expression: &String, position: Position) -> String {
+        match (position, expression.parse::<u32>()) {
+            (Position::Starting, Ok(minute)) => minute.to_string(),
+            _ => self.get_single_item_description(expression),
+        }
+    }
+
+    fn get_compact_item_description(&self, expression: &str) -> String {
+        format!(":{}", format_minutes(expression))
+    }
+
     fn get_description_format(&self, expression: &String) -> String {
         if expression == "0" {
             "".to_string()
         } else {
-            t!("messages.at_x") + &self.get_space() + &Self::min_plural(expression) +
-                &self.get_space() + &t!("messages.past_the_hour")
+            t!("messages.at_x_minutes_past_the_hour").replace("{1}", &plural::unit("minute", expression))
         }
     }
 
     fn need_space_between_words(&self) -> bool {
-        self.options.need_space_between_words
+        need_space(self.options)
     }
 
-    fn get_space(&self) -> String {
-        Self::get_space_opt(&self.options)
+    fn verbosity(&self) -> Verbosity {
+        self.options.verbosity
     }
+
 }
 
 impl DescriptionBuilder<'_> for MonthDescriptionBuilder<'_> {
@@ -348,19 +573,12 @@ impl DescriptionBuilder<'_> for MonthDescriptionBuilder<'_> {
         if omit_separator {
             format
         } else {
-            format!(", {}", format)
+            separator() + &format
         }
     }
 
     fn get_interval_description_format(&self, expression: &String) -> String {
-        let month_str = t!("month");
-        let months_str = t!("months");
-        let plural_str = Self::plural(expression, &month_str, &months_str);
-        let gdf = format!(", {}{}{}", t!("messages.every_x"), self.get_space(), plural_str);
-
-        let mut vars = HashMap::new();
-        vars.insert("0".to_string(), expression.to_string());
-        strfmt(&gdf, &vars).unwrap()
+        separator() + &every_x("month", expression, self.options)
     }
 
     fn get_single_item_description(&self, expression: &String) -> String {
@@ -369,17 +587,39 @@ impl DescriptionBuilder<'_> for MonthDescriptionBuilder<'_> {
         t!(month_key)
     }
 
+    fn get_item_description_at(&self, expression: &String, position: Position) -> String {
+        let month_num = expression.parse::<usize>().unwrap();
+        inflection::inflect("month", MONTHS_ARR[month_num - 1], position, self.get_single_item_description(expression))
+    }
+
+    fn get_compact_item_description(&self, expression: &str) -> String {
+        let month_num = expression.parse::<usize>().unwrap();
+        t!(&format!("abbreviations.{}", MONTHS_ARR[month_num - 1]))
+    }
+
     fn get_description_format(&self, _: &String) -> String {
-        format!(", {}", t!("messages.only_in_month"))
+        separator() + &t!("messages.only_in_month")
+    }
+
+    // A locale whose "only in" message ends with a preposition may give a range that reads
+    // after it, as the Arabic "في الفترة من نوفمبر إلى فبراير".
+    fn get_range_format(&self) -> String {
+        inflection::translate_optional("messages.month_list_range_description_format")
+            .unwrap_or_else(|| self.get_between_description_format(true))
+    }
+
+    fn get_starting_description_format(&self, _: &String) -> String {
+        t!("messages.in_x")
     }
 
     fn need_space_between_words(&self) -> bool {
-        self.options.need_space_between_words
+        need_space(self.options)
     }
 
-    fn get_space(&self) -> String {
-        Self::get_space_opt(&self.options)
+    fn verbosity(&self) -> Verbosity {
+        self.options.verbosity
     }
+
 }
 
 impl DescriptionBuilder<'_> for SecondsDescriptionBuilder<'_> {
@@ -387,25 +627,33 @@ impl DescriptionBuilder<'_> for SecondsDescriptionBuilder<'_> {
         t!("messages.seconds_through_past_the_minute")
     }
 
-    fn get_interval_description_format(&self, _: &String) -> String {
-        t!("messages.every_x_seconds")
+    fn get_range_format(&self) -> String {
+        list_range_format()
+    }
+
+    fn get_interval_description_format(&self, expression: &String) -> String {
+        if expression == "1" && self.verbosity() != Verbosity::Verbose {
+            return t!("messages.every_second");
+        }
+        every_x("second", expression, self.options)
     }
 
     fn get_single_item_description(&self, expression: &String) -> String {
         expression.to_string()
     }
 
-    fn get_description_format(&self, _: &String) -> String {
-        t!("messages.at_x_seconds_past_the_minute")
+    fn get_description_format(&self, expression: &String) -> String {
+        t!("messages.at_x_seconds_past_the_minute").replace("{1}", &plural::unit("second", expression))
     }
 
     fn need_space_between_words(&self) -> bool {
-        self.options.need_space_between_words
+        need_space(self.options)
     }
 
-    fn get_space(&self) -> String {
-        Self::get_space_opt(&self.options)
+    fn verbosity(&self) -> Verbosity {
+        self.options.verbosity
     }
+
 }
 
 impl DescriptionBuilder<'_> for YearDescriptionBuilder<'_> {
@@ -414,18 +662,12 @@ impl DescriptionBuilder<'_> for YearDescriptionBuilder<'_> {
         if omit_separator {
             format
         } else {
-            format!(", {}", format)
+            separator() + &format
         }
     }
 
     fn get_interval_description_format(&self, expression: &String) -> String {
-        let year_str = t!("year");
-        let years_str = t!("years");
-        let plural_str = Self::plural(expression, &year_str, &years_str);
-        let gdf = format!(", {}{}{}", t!("messages.every_x"), self.get_space(), plural_str);
-        let mut vars = HashMap::new();
-        vars.insert("0".to_string(), expression.to_string());
-        strfmt(&gdf, &vars).unwrap()
+        separator() + &every_x("year", expression, self.options)
     }
 
     fn get_single_item_description(&self, expression: &String) -> String {
@@ -434,14 +676,19 @@ impl DescriptionBuilder<'_> for YearDescriptionBuilder<'_> {
     }
 
     fn get_description_format(&self, _: &String) -> String {
-        format!(", {}", t!("messages.only_in_year"))
+        separator() + &t!("messages.only_in_year")
+    }
+
+    fn get_starting_description_format(&self, _: &String) -> String {
+        t!("messages.in_x")
     }
 
     fn need_space_between_words(&self) -> bool {
-        self.options.need_space_between_words
+        need_space(self.options)
     }
 
-    fn get_space(&self) -> String {
-        Self::get_space_opt(&self.options)
+    fn verbosity(&self) -> Verbosity {
+        self.options.verbosity
     }
+
 }
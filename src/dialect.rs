@@ -0,0 +1,401 @@
+//! Conversion between cron dialects. Each dialect differs in which fields it has, how it
+//! numbers the days of the week, whether one of day of month and day of week must be `?`
+//! and which of the Quartz `L`, `W` and `#` constructs it understands. Whatever cannot be
+//! carried over is reported as a [`Loss`] rather than silently dropped.
+
+use crate::cronparser::cron_expression_descriptor::ParseException;
+use crate::cronparser::Options;
+use crate::schedule::{
+    format_values, parse_item, replace_names, wrap_around_range, DAY_OF_WEEK_NAMES, MONTH_NAMES,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Dialect {
+    /// POSIX crontab: `min hour dom month dow`, numbers only, Sunday is 0 (or 7).
+    Unix,
+    /// Vixie cron and its descendants (cronie, Kubernetes CronJob): like Unix with names
+    /// and `@daily`-style macros.
+    Vixie,
+    /// `sec min hour dom month dow [year]`, Sunday is 1, one of dom and dow must be `?`.
+    Quartz,
+    /// Spring's `CronExpression`: `sec min hour dom month dow`, Sunday is 0 (or 7).
+    Spring,
+    /// Amazon EventBridge: `min hour dom month dow year`, Sunday is 1, one of dom and dow
+    /// must be `?`. The `cron(...)` wrapper is accepted on input.
+    EventBridge,
+}
+
+/// What a conversion could not carry over to the target dialect.
+#[derive(Debug, PartialEq)]
+pub enum Loss {
+    /// Seconds other than 0 were dropped; the target fires at second 0.
+    Seconds(String),
+    /// A year restriction was dropped.
+    Year(String),
+    /// An `L`, `W`, `LW` or `L-n` day of month was dropped.
+    DayOfMonth(String),
+    /// An `L` or `#` day of week was reduced to every such weekday.
+    DayOfWeek(String),
+    /// Day of month and day of week were both restricted; the target either cannot
+    /// express that (the day of week was dropped) or matches days differently (any of
+    /// them in Unix and Vixie, both of them in Spring).
+    DayCombination,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Conversion {
+    pub expression: String,
+    pub losses: Vec<Loss>,
+}
+
+// Fields of an expression in any dialect; `None` when the dialect has no such field.
+struct Fields {
+    seconds: Option<String>,
+    minutes: String,
+    hours: String,
+    day_of_month: String,
+    month: String,
+    day_of_week: String,
+    year: Option<String>,
+}
+
+impl Dialect {
+    pub fn has_seconds(&self) -> bool {
+        matches!(self, Dialect::Quartz | Dialect::Spring)
+    }
+
+    pub fn has_year(&self) -> bool {
+        matches!(self, Dialect::Quartz | Dialect::EventBridge)
+    }
+
+    /// Whether `L`, `W` and `#` are understood.
+    pub fn has_quartz_extensions(&self) -> bool {
+        matches!(
+            self,
+            Dialect::Quartz | Dialect::Spring | Dialect::EventBridge
+        )
+    }
+
+    /// Whether a range may end before its start and wrap around, as in `FRI-MON` or
+    /// `22-2`.
+    pub fn allows_wrap_around(&self) -> bool {
+        *self == Dialect::Quartz
+    }
+
+    pub fn has_names(&self) -> bool {
+        *self != Dialect::Unix
+    }
+
+    /// Whether one of day of month and day of week must be `?`.
+    pub fn requires_question_mark(&self) -> bool {
+        matches!(self, Dialect::Quartz | Dialect::EventBridge)
+    }
+
+    /// Whether a day matching either the restricted day of month or the restricted day of
+    /// week fires, rather than one matching both.
+    pub fn matches_any_day(&self) -> bool {
+        matches!(self, Dialect::Unix | Dialect::Vixie)
+    }
+
+    /// The number of Sunday.
+    pub fn first_day_of_week(&self) -> u32 {
+        match self {
+            Dialect::Quartz | Dialect::EventBridge => 1,
+            _ => 0,
+        }
+    }
+
+    /// Description options matching the dialect's day-of-week numbering.
+    pub fn options(&self) -> Options<'static> {
+        Options {
+            zero_based_day_of_week: self.first_day_of_week() == 0,
+            match_any_day: self.matches_any_day(),
+            ..Options::options()
+        }
+    }
+}
+
+pub fn convert(expression: &str, from: Dialect, to: Dialect) -> Result<Conversion, ParseException> {
+    let fields = split_fields(expression, from)?;
+    let mut losses = Vec::new();
+    let mut converted = Vec::new();
+
+    let expand = |field: &str, min, max| {
+        if to.allows_wrap_around() {
+            field.to_string()
+        } else {
+            expand_wrap_around(field, min, max)
+        }
+    };
+    match &fields.seconds {
+        _ if to.has_seconds() => {
+            converted.push(expand(fields.seconds.as_deref().unwrap_or("0"), 0, 59))
+        }
+        Some(seconds) if seconds != "0" => losses.push(Loss::Seconds(seconds.clone())),
+        _ => {}
+    }
+    converted.push(expand(&fields.minutes, 0, 59));
+    converted.push(expand(&fields.hours, 0, 23));
+
+    let mut day_of_month =
+        convert_day_of_month(&expand(&fields.day_of_month, 1, 31), to, &mut losses);
+    let mut day_of_week =
+        convert_day_of_week(expression, &fields.day_of_week, from, to, &mut losses)?;
+    let restricted = |field: &str| field != "*" && field != "?";
+    if restricted(&day_of_month) && restricted(&day_of_week) {
+        // Quartz and EventBridge sources cannot get here, `split_fields` rejects them.
+        if to.requires_question_mark() {
+            losses.push(Loss::DayCombination);
+            day_of_week = "?".to_string();
+        } else if from.matches_any_day() != to.matches_any_day() {
+            losses.push(Loss::DayCombination);
+        }
+    }
+    if to.requires_question_mark() {
+        if restricted(&day_of_week) {
+            day_of_month = "?".to_string();
+        } else {
+            day_of_week = "?".to_string();
+        }
+    } else {
+        day_of_month = day_of_month.replace('?', "*");
+        day_of_week = day_of_week.replace('?', "*");
+    }
+    converted.push(day_of_month);
+    let month = replace_names(&fields.month, &MONTH_NAMES, 1);
+    let expanded_month = expand(&month, 1, 12);
+    converted.push(if to.has_names() && expanded_month == month {
+        fields.month.clone()
+    } else {
+        expanded_month
+    });
+    converted.push(day_of_week);
+
+    match &fields.year {
+        Some(year) if year != "*" && to.has_year() => converted.push(year.clone()),
+        Some(year) if year != "*" => losses.push(Loss::Year(year.clone())),
+        _ if to == Dialect::EventBridge => converted.push("*".to_string()),
+        _ => {}
+    }
+
+    Ok(Conversion {
+        expression: converted.join(" "),
+        losses,
+    })
+}
+
+fn parse_error(expression: &str, error_offset: u8) -> ParseException {
+    ParseException {
+        s: expression.to_string(),
+        error_offset,
+    }
+}
+
+fn split_fields(expression: &str, dialect: Dialect) -> Result<Fields, ParseException> {
+    let mut trimmed = expression.trim();
+    if dialect == Dialect::EventBridge {
+        if let Some(inner) = trimmed
+            .strip_prefix("cron(")
+            .and_then(|e| e.strip_suffix(')'))
+        {
+            trimmed = inner;
+        }
+    }
+    if dialect == Dialect::Vixie && trimmed.starts_with('@') {
+        trimmed = match trimmed {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            _ => return Err(parse_error(expression, 0)),
+        };
+    }
+
+    let parts: Vec<String> = trimmed.split_whitespace().map(|p| p.to_string()).collect();
+    let fields = match (dialect, parts.len()) {
+        (Dialect::Unix | Dialect::Vixie, 5) => Fields {
+            seconds: None,
+            minutes: parts[0].clone(),
+            hours: parts[1].clone(),
+            day_of_month: parts[2].clone(),
+            month: parts[3].clone(),
+            day_of_week: parts[4].clone(),
+            year: None,
+        },
+        (Dialect::Quartz, 6 | 7) | (Dialect::Spring, 6) => Fields {
+            seconds: Some(parts[0].clone()),
+            minutes: parts[1].clone(),
+            hours: parts[2].clone(),
+            day_of_month: parts[3].clone(),
+            month: parts[4].clone(),
+            day_of_week: parts[5].clone(),
+            year: parts.get(6).cloned(),
+        },
+        (Dialect::EventBridge, 6) => Fields {
+            seconds: None,
+            minutes: parts[0].clone(),
+            hours: parts[1].clone(),
+            day_of_month: parts[2].clone(),
+            month: parts[3].clone(),
+            day_of_week: parts[4].clone(),
+            year: Some(parts[5].clone()),
+        },
+        (_, len) => return Err(parse_error(expression, if len < 5 { 0 } else { 7 })),
+    };
+
+    // Exactly one of day of month and day of week must be `?`.
+    if dialect.requires_question_mark()
+        && (fields.day_of_month == "?") == (fields.day_of_week == "?")
+    {
+        return Err(parse_error(expression, 5));
+    }
+    if !dialect.requires_question_mark()
+        && dialect != Dialect::Spring
+        && (fields.day_of_month == "?" || fields.day_of_week == "?")
+    {
+        return Err(parse_error(expression, 5));
+    }
+    if !dialect.allows_wrap_around() {
+        let first_day_of_week = dialect.first_day_of_week() as usize;
+        let checked = [
+            (1, fields.minutes.clone()),
+            (2, fields.hours.clone()),
+            (3, fields.day_of_month.clone()),
+            (4, replace_names(&fields.month, &MONTH_NAMES, 1)),
+            (
+                5,
+                replace_names(&fields.day_of_week, &DAY_OF_WEEK_NAMES, first_day_of_week),
+            ),
+        ];
+        let seconds = fields.seconds.iter().map(|s| (0, s.clone()));
+        for (offset, field) in seconds.chain(checked) {
+            if field
+                .split(',')
+                .any(|item| wrap_around_range(item).is_some())
+            {
+                return Err(parse_error(expression, offset));
+            }
+        }
+    }
+    Ok(fields)
+}
+
+// Replaces the wrap-around ranges of a field with the values they cover, `22-2` becomes
+// `0-2,22,23`.
+fn expand_wrap_around(field: &str, min: u32, max: u32) -> String {
+    let items: Vec<String> = field
+        .split(',')
+        .map(
+            |item| match wrap_around_range(item).and(parse_item(item, min, max)) {
+                Some(mut values) => {
+                    values.sort_unstable();
+                    format_values(&values, min, max, None)
+                }
+                None => item.to_string(),
+            },
+        )
+        .collect();
+    items.join(",")
+}
+
+fn convert_day_of_month(field: &str, to: Dialect, losses: &mut Vec<Loss>) -> String {
+    if to.has_quartz_extensions() || field == "*" || field == "?" {
+        return field.to_string();
+    }
+    let mut kept = Vec::new();
+    for item in field.split(',') {
+        if item.contains('L') || item.contains('W') {
+            losses.push(Loss::DayOfMonth(item.to_string()));
+        } else {
+            kept.push(item);
+        }
+    }
+    if kept.is_empty() {
+        "*".to_string()
+    } else {
+        kept.join(",")
+    }
+}
+
+// Renumbers the days of the week when the dialects number them differently, keeping
+// names where the target understands them.
+fn convert_day_of_week(
+    expression: &str,
+    field: &str,
+    from: Dialect,
+    to: Dialect,
+    losses: &mut Vec<Loss>,
+) -> Result<String, ParseException> {
+    if field == "*" || field == "?" {
+        return Ok(field.to_string());
+    }
+    let renumber = from.first_day_of_week() != to.first_day_of_week();
+    let error = || parse_error(expression, 5);
+    // Source day (number or name) to the target's number for the same day.
+    let day = |token: &str| -> Result<u32, ParseException> {
+        let number = replace_names(token, &DAY_OF_WEEK_NAMES, from.first_day_of_week() as usize)
+            .parse::<u32>()
+            .map_err(|_| error())?;
+        let sunday_based = match from.first_day_of_week() {
+            0 if number <= 7 => number % 7,
+            1 if (1..=7).contains(&number) => number - 1,
+            _ => return Err(error()),
+        };
+        Ok(sunday_based + to.first_day_of_week())
+    };
+    let keep_token = |token: &str| -> Result<String, ParseException> {
+        if to.has_names() && token.chars().all(|c| c.is_ascii_alphabetic()) {
+            Ok(token.to_uppercase())
+        } else {
+            day(token).map(|d| d.to_string())
+        }
+    };
+
+    let mut items = Vec::new();
+    let mut plain_days = Vec::new();
+    for item in field.split(',') {
+        let numbers = replace_names(item, &DAY_OF_WEEK_NAMES, from.first_day_of_week() as usize);
+        let expand = !to.allows_wrap_around() && wrap_around_range(&numbers).is_some();
+        let special = if item == "L" {
+            Some(("SAT", ""))
+        } else if let Some((d, nth)) = item.split_once('#') {
+            Some((d, nth))
+        } else {
+            item.strip_suffix('L').map(|d| (d, "L"))
+        };
+        match special {
+            Some((d, _)) if !to.has_quartz_extensions() => {
+                losses.push(Loss::DayOfWeek(item.to_string()));
+                items.push(keep_token(d)?);
+            }
+            Some((d, "")) => items.push(keep_token(d)?),
+            Some((d, "L")) => items.push(format!("{}L", keep_token(d)?)),
+            Some((d, nth)) => items.push(format!("{}#{}", keep_token(d)?, nth)),
+            None if !expand && to.has_names() && !item.chars().any(|c| c.is_ascii_digit()) => {
+                items.push(item.to_uppercase())
+            }
+            None if !expand && !renumber && !item.chars().any(|c| c.is_ascii_alphabetic()) => {
+                items.push(item.to_string())
+            }
+            None => {
+                // Ranges and steps may not survive renumbering, expand them instead.
+                let (min, max) = if from.first_day_of_week() == 0 {
+                    (0, 7)
+                } else {
+                    (1, 7)
+                };
+                for value in parse_item(&numbers, min, max).ok_or_else(error)? {
+                    plain_days.push(day(&value.to_string())?);
+                }
+            }
+        }
+    }
+    if !plain_days.is_empty() {
+        plain_days.sort_unstable();
+        plain_days.dedup();
+        let first = to.first_day_of_week();
+        items.insert(0, format_values(&plain_days, first, first + 6, None));
+    }
+    Ok(items.join(","))
+}
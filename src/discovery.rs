@@ -0,0 +1,197 @@
+//! Discovery of cron schedules in YAML files: Kubernetes `CronJob` manifests
+//! (`spec.schedule`, with `spec.timeZone`) and GitHub Actions workflows
+//! (`on.schedule[].cron`). Every schedule found is reported with where it was found and
+//! its description, so a whole repository can be listed at once.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use serde_yaml::Value;
+use walkdir::WalkDir;
+
+use crate::cronparser::cron_expression_descriptor::{
+    get_description_cron_options_locale, ParseException,
+};
+use crate::cronparser::Options;
+use crate::dialect::{self, Dialect};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Source {
+    KubernetesCronJob,
+    GitHubActions,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DiscoveredSchedule {
+    pub file: PathBuf,
+    /// Index of the document within a multi-document file, starting at 0.
+    pub document: usize,
+    /// Where the expression is within the document, e.g. `on.schedule[1].cron`.
+    pub path: String,
+    pub source: Source,
+    pub expression: String,
+    /// `spec.timeZone` for a `CronJob`, `UTC` for a workflow. `None` means the time zone of
+    /// the Kubernetes controller manager.
+    pub time_zone: Option<String>,
+    pub description: Result<String, ParseException>,
+}
+
+#[derive(Debug)]
+pub enum DiscoveryError {
+    Io(PathBuf, io::Error),
+    Yaml(PathBuf, serde_yaml::Error),
+}
+
+/// Everything found under a directory. A file that cannot be read or parsed is reported
+/// in `errors` and does not stop the scan.
+#[derive(Debug, Default)]
+pub struct ScanReport {
+    pub schedules: Vec<DiscoveredSchedule>,
+    pub errors: Vec<DiscoveryError>,
+}
+
+/// Scans every `.yml` and `.yaml` file under `root`, in file name order.
+pub fn scan_dir(root: &Path, options: &Options, locale: &str) -> ScanReport {
+    let mut report = ScanReport::default();
+    let entries = WalkDir::new(root)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| e.file_name() != ".git");
+    for entry in entries {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                let path = e.path().unwrap_or(root).to_path_buf();
+                report.errors.push(DiscoveryError::Io(path, e.into()));
+                continue;
+            }
+        };
+        let is_yaml = entry
+            .path()
+            .extension()
+            .is_some_and(|ext| ext == "yml" || ext == "yaml");
+        if !entry.file_type().is_file() || !is_yaml {
+            continue;
+        }
+        match scan_file(entry.path(), options, locale) {
+            Ok(schedules) => report.schedules.extend(schedules),
+            Err(e) => report.errors.push(e),
+        }
+    }
+    report
+}
+
+pub fn scan_file(
+    file: &Path,
+    options: &Options,
+    locale: &str,
+) -> Result<Vec<DiscoveredSchedule>, DiscoveryError> {
+    let content =
+        fs::read_to_string(file).map_err(|e| DiscoveryError::Io(file.to_path_buf(), e))?;
+    scan_str(file, &content, options, locale)
+}
+
+/// Scans the YAML documents in `content`; `file` is only used for reporting.
+pub fn scan_str(
+    file: &Path,
+    content: &str,
+    options: &Options,
+    locale: &str,
+) -> Result<Vec<DiscoveredSchedule>, DiscoveryError> {
+    let mut found = Vec::new();
+    for (index, document) in serde_yaml::Deserializer::from_str(content).enumerate() {
+        let value = Value::deserialize(document)
+            .map_err(|e| DiscoveryError::Yaml(file.to_path_buf(), e))?;
+        let mut scanner = Scanner {
+            file,
+            document: index,
+            options,
+            locale,
+            found: &mut found,
+        };
+        scanner.scan_document(&value, "");
+    }
+    Ok(found)
+}
+
+struct Scanner<'a> {
+    file: &'a Path,
+    document: usize,
+    options: &'a Options<'a>,
+    locale: &'a str,
+    found: &'a mut Vec<DiscoveredSchedule>,
+}
+
+impl Scanner<'_> {
+    fn scan_document(&mut self, value: &Value, prefix: &str) {
+        match value.get("kind").and_then(Value::as_str) {
+            Some("CronJob") => self.scan_cron_job(value, prefix),
+            // `kubectl get -o yaml` wraps resources in a list.
+            Some("List") => {
+                if let Some(items) = value.get("items").and_then(Value::as_sequence) {
+                    for (i, item) in items.iter().enumerate() {
+                        self.scan_document(item, &format!("{}items[{}].", prefix, i));
+                    }
+                }
+            }
+            Some(_) => {}
+            None => self.scan_workflow(value, prefix),
+        }
+    }
+
+    fn scan_cron_job(&mut self, value: &Value, prefix: &str) {
+        let spec = value.get("spec");
+        if let Some(schedule) = spec.and_then(|s| s.get("schedule")).and_then(Value::as_str) {
+            let time_zone = spec
+                .and_then(|s| s.get("timeZone"))
+                .and_then(Value::as_str)
+                .map(|tz| tz.to_string());
+            self.push(
+                format!("{}spec.schedule", prefix),
+                Source::KubernetesCronJob,
+                schedule,
+                time_zone,
+            );
+        }
+    }
+
+    fn scan_workflow(&mut self, value: &Value, prefix: &str) {
+        // YAML 1.1 parsers read an unquoted `on` key as `true`.
+        let on = value
+            .get("on")
+            .or_else(|| value.as_mapping().and_then(|m| m.get(&Value::Bool(true))));
+        let schedules = on
+            .and_then(|on| on.get("schedule"))
+            .and_then(Value::as_sequence);
+        for (i, entry) in schedules.into_iter().flatten().enumerate() {
+            if let Some(cron) = entry.get("cron").and_then(Value::as_str) {
+                self.push(
+                    format!("{}on.schedule[{}].cron", prefix, i),
+                    Source::GitHubActions,
+                    cron,
+                    Some("UTC".to_string()),
+                );
+            }
+        }
+    }
+
+    fn push(&mut self, path: String, source: Source, expression: &str, time_zone: Option<String>) {
+        self.found.push(DiscoveredSchedule {
+            file: self.file.to_path_buf(),
+            document: self.document,
+            path,
+            source,
+            expression: expression.to_string(),
+            time_zone,
+            description: self.describe(expression),
+        });
+    }
+
+    // Both schedulers follow Vixie cron, so macros such as `@hourly` are expanded first.
+    fn describe(&self, expression: &str) -> Result<String, ParseException> {
+        let conversion = dialect::convert(expression, Dialect::Vixie, Dialect::Vixie)?;
+        get_description_cron_options_locale(&conversion.expression, self.options, self.locale)
+    }
+}
@@ -0,0 +1,811 @@
+//! iCalendar (RFC 5545) export of cron expressions, so that a schedule can be
+//! subscribed to from a calendar application, and import of recurrence rules as cron
+//! expressions.
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::cronparser::cron_expression_descriptor::{get_description_cron_options, ParseException};
+use crate::cronparser::Options;
+use crate::schedule::{self, DateTime, DayOfMonthItem, DayOfWeekItem, Schedule, DAY_OF_WEEK_NAMES};
+
+const WEEKDAY_CODES: [&str; 7] = ["SU", "MO", "TU", "WE", "TH", "FR", "SA"];
+// RFC 5545 limits BYSETPOS to +/-366.
+const MAX_SET_POSITION: usize = 366;
+const MAX_LINE_OCTETS: usize = 75;
+
+#[derive(Debug, PartialEq)]
+pub enum ConversionError {
+    Parse(ParseException),
+    /// The expression is valid but uses a construct the target format cannot represent.
+    Unsupported(String),
+}
+
+impl From<ParseException> for ConversionError {
+    fn from(e: ParseException) -> Self {
+        ConversionError::Parse(e)
+    }
+}
+
+/// Cron equivalent of a recurrence rule. The union of `expressions` is the rule; there is
+/// more than one when several `BYDAY` ordinals each need their own `#` or `L`.
+#[derive(Debug, PartialEq)]
+pub struct CronImport {
+    pub expressions: Vec<String>,
+    /// Description of each expression, in the current locale.
+    pub descriptions: Vec<String>,
+}
+
+pub struct VEventOptions {
+    pub uid: String,
+    /// Occurrences are searched from this (local) time; DTSTART is the first one found.
+    pub start: DateTime,
+    /// UTC creation time of the event.
+    pub dtstamp: DateTime,
+    /// IANA zone for DTSTART, e.g. `Europe/Berlin`, from which the UNTIL of a year range is
+    /// converted to UTC. Floating local time when `None`.
+    pub time_zone: Option<String>,
+    /// Defaults to the description of the expression in the current locale.
+    pub summary: Option<String>,
+}
+
+impl VEventOptions {
+    pub fn new(uid: &str, start: DateTime) -> VEventOptions {
+        VEventOptions {
+            uid: uid.to_string(),
+            start,
+            dtstamp: DateTime::now_utc(),
+            time_zone: None,
+            summary: None,
+        }
+    }
+}
+
+/// Converts a cron expression into one or more RRULE values whose union is the schedule.
+/// Several rules are needed when day of month and day of week are both restricted and either
+/// may match, or when `L`/`#` day-of-week items have to be selected with their own BYSETPOS.
+pub fn to_rrules(expression: &str, options: &Options) -> Result<Vec<String>, ConversionError> {
+    let schedule = schedule::parse(expression, options)?;
+    split_schedule(&schedule)
+        .iter()
+        .map(|part| rrule_for(part, None))
+        .collect()
+}
+
+/// Exports a cron expression as a VCALENDAR with one VEVENT per RRULE.
+pub fn to_ics(
+    expression: &str,
+    options: &Options,
+    event: &VEventOptions,
+) -> Result<String, ConversionError> {
+    let schedule = schedule::parse(expression, options)?;
+    let parts = split_schedule(&schedule);
+    let summary = match &event.summary {
+        Some(summary) => summary.clone(),
+        None => get_description_cron_options(expression, options)?,
+    };
+
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//cron-descriptor//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+    for (i, part) in parts.iter().enumerate() {
+        let rrule = rrule_for(part, event.time_zone.as_deref())?;
+        let first = part.next_occurrence(&event.start).ok_or_else(|| {
+            ConversionError::Unsupported(format!("no occurrence after {}", event.start))
+        })?;
+        let uid = if parts.len() > 1 {
+            format!("{}-{}", event.uid, i + 1)
+        } else {
+            event.uid.clone()
+        };
+        let dtstart = match &event.time_zone {
+            Some(tz) => format!("DTSTART;TZID={}:{}", tz, format_date_time(&first)),
+            None => format!("DTSTART:{}", format_date_time(&first)),
+        };
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}", uid));
+        lines.push(format!("DTSTAMP:{}Z", format_date_time(&event.dtstamp)));
+        lines.push(dtstart);
+        lines.push(format!("RRULE:{}", rrule));
+        lines.push(format!("SUMMARY:{}", escape_text(&summary)));
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    let mut ics = String::new();
+    for line in lines {
+        ics.push_str(&fold_line(&line));
+        ics.push_str("\r\n");
+    }
+    Ok(ics)
+}
+
+/// Converts a recurrence rule into cron expressions, with their descriptions. `rule` is
+/// either a bare RRULE value or iCalendar content lines; a `DTSTART` line then supplies
+/// the parts of the schedule the rule leaves implicit, as RFC 5545 section 3.3.10 does.
+/// Without it the time of day defaults to midnight.
+pub fn from_rrule(rule: &str, options: &Options) -> Result<CronImport, ConversionError> {
+    let (parts, dtstart) = parse_rrule_lines(rule)?;
+    let expressions = RRuleImporter {
+        parts: &parts,
+        dtstart: dtstart.as_ref(),
+    }
+    .cron_expressions()?;
+    let descriptions = expressions
+        .iter()
+        .map(|e| get_description_cron_options(e, options))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(CronImport {
+        expressions,
+        descriptions,
+    })
+}
+
+// One schedule per RRULE: plain days of month and plain days of week each form one rule,
+// every special (`L`, `LW`, `W`, `#`, `nL`) gets a rule of its own.
+fn split_schedule(schedule: &Schedule) -> Vec<Schedule> {
+    // Days matching both fields are the intersection a single rule gives.
+    if !schedule.match_any_day
+        && !schedule.days_of_month.is_empty()
+        && !schedule.days_of_week.is_empty()
+    {
+        return vec![schedule.clone()];
+    }
+    let mut parts = Vec::new();
+    let with_days =
+        |days_of_month: Vec<DayOfMonthItem>, days_of_week: Vec<DayOfWeekItem>| Schedule {
+            days_of_month,
+            days_of_week,
+            ..schedule.clone()
+        };
+
+    let (plain_dom, special_dom): (Vec<_>, Vec<_>) =
+        schedule.days_of_month.iter().cloned().partition(|i| {
+            matches!(
+                i,
+                DayOfMonthItem::Day(_) | DayOfMonthItem::LastDay | DayOfMonthItem::LastDayOffset(_)
+            )
+        });
+    if !plain_dom.is_empty() {
+        parts.push(with_days(plain_dom, vec![]));
+    }
+    parts.extend(special_dom.into_iter().map(|i| with_days(vec![i], vec![])));
+
+    let (plain_dow, special_dow): (Vec<_>, Vec<_>) = schedule
+        .days_of_week
+        .iter()
+        .cloned()
+        .partition(|i| matches!(i, DayOfWeekItem::Day(_)));
+    if !plain_dow.is_empty() {
+        parts.push(with_days(vec![], plain_dow));
+    }
+    parts.extend(special_dow.into_iter().map(|i| with_days(vec![], vec![i])));
+
+    if parts.is_empty() {
+        parts.push(schedule.clone());
+    }
+    parts
+}
+
+// `time_zone` is that of DTSTART, `None` for floating local time.
+fn rrule_for(schedule: &Schedule, time_zone: Option<&str>) -> Result<String, ConversionError> {
+    let plain_days = schedule.days_of_month.iter().all(|i| {
+        matches!(
+            i,
+            DayOfMonthItem::Day(_) | DayOfMonthItem::LastDay | DayOfMonthItem::LastDayOffset(_)
+        )
+    }) && schedule
+        .days_of_week
+        .iter()
+        .all(|i| matches!(i, DayOfWeekItem::Day(_)));
+    if !schedule.days_of_month.is_empty() && !schedule.days_of_week.is_empty() && !plain_days {
+        return Err(ConversionError::Unsupported(
+            "days matching both a special day of month and day of week have no RRULE equivalent"
+                .to_string(),
+        ));
+    }
+    let mut rule = match (
+        schedule.days_of_month.first(),
+        schedule.days_of_week.first(),
+    ) {
+        (Some(DayOfMonthItem::NearestWeekday(day)), _) => {
+            return Err(ConversionError::Unsupported(format!(
+                "the weekday nearest day {} (W) has no RRULE equivalent",
+                day
+            )))
+        }
+        (Some(DayOfMonthItem::LastWeekday), _) => {
+            set_position_rule(schedule, "MO,TU,WE,TH,FR", -1)?
+        }
+        (_, Some(DayOfWeekItem::Last(day))) => {
+            set_position_rule(schedule, WEEKDAY_CODES[*day as usize], -1)?
+        }
+        (_, Some(DayOfWeekItem::Nth(day, nth))) => {
+            set_position_rule(schedule, WEEKDAY_CODES[*day as usize], *nth as i32)?
+        }
+        _ => simple_rule(schedule),
+    };
+
+    if !schedule.years.wildcard {
+        let years = &schedule.years.values;
+        let (first, last) = (years[0], years[years.len() - 1]);
+        if (last - first + 1) as usize != years.len() {
+            return Err(ConversionError::Unsupported(
+                "years that are not a single range have no RRULE equivalent".to_string(),
+            ));
+        }
+        // The first year is enforced by DTSTART.
+        rule.push(format!("UNTIL={}", until(last as i32, time_zone)?));
+    }
+    Ok(rule.join(";"))
+}
+
+// Daily, weekly or monthly rule, made as coarse as possible by letting FREQ take the
+// place of the finest unrestricted time field.
+fn simple_rule(schedule: &Schedule) -> Vec<String> {
+    // BYMONTHDAY is not allowed in weekly rules.
+    let date_freq = if !schedule.days_of_month.is_empty() {
+        "MONTHLY"
+    } else if !schedule.days_of_week.is_empty() {
+        "WEEKLY"
+    } else {
+        "DAILY"
+    };
+    let (freq, by_hour, by_minute, by_second) = if schedule.seconds.wildcard {
+        (
+            "SECONDLY",
+            !schedule.hours.wildcard,
+            !schedule.minutes.wildcard,
+            false,
+        )
+    } else if schedule.minutes.wildcard {
+        ("MINUTELY", !schedule.hours.wildcard, false, true)
+    } else if schedule.hours.wildcard {
+        ("HOURLY", false, true, true)
+    } else {
+        (date_freq, true, true, true)
+    };
+
+    let mut rule = vec![format!("FREQ={}", freq)];
+    rule.extend(date_parts(schedule));
+    if by_hour {
+        rule.push(format!("BYHOUR={}", join(&schedule.hours.values)));
+    }
+    if by_minute {
+        rule.push(format!("BYMINUTE={}", join(&schedule.minutes.values)));
+    }
+    if by_second && schedule.seconds.values != [0] {
+        rule.push(format!("BYSECOND={}", join(&schedule.seconds.values)));
+    }
+    rule
+}
+
+// Monthly rule picking the nth (or, when negative, the last) of the matching days with
+// BYSETPOS. Every matching day contributes all its times to the set, so the positions
+// span a whole day's worth of occurrences.
+fn set_position_rule(
+    schedule: &Schedule,
+    by_day: &str,
+    nth: i32,
+) -> Result<Vec<String>, ConversionError> {
+    let per_day =
+        schedule.hours.values.len() * schedule.minutes.values.len() * schedule.seconds.values.len();
+    if per_day * nth.unsigned_abs() as usize > MAX_SET_POSITION {
+        return Err(ConversionError::Unsupported(format!(
+            "{} occurrences per day cannot be selected with BYSETPOS",
+            per_day
+        )));
+    }
+    let positions: Vec<i64> = if nth < 0 {
+        (-(per_day as i64)..=-1).collect()
+    } else {
+        let first = (nth as i64 - 1) * per_day as i64 + 1;
+        (first..first + per_day as i64).collect()
+    };
+
+    let mut rule = vec!["FREQ=MONTHLY".to_string()];
+    if !schedule.months.wildcard {
+        rule.push(format!("BYMONTH={}", join(&schedule.months.values)));
+    }
+    rule.push(format!("BYDAY={}", by_day));
+    rule.push(format!("BYHOUR={}", join(&schedule.hours.values)));
+    rule.push(format!("BYMINUTE={}", join(&schedule.minutes.values)));
+    if schedule.seconds.values != [0] {
+        rule.push(format!("BYSECOND={}", join(&schedule.seconds.values)));
+    }
+    rule.push(format!("BYSETPOS={}", join(&positions)));
+    Ok(rule)
+}
+
+fn date_parts(schedule: &Schedule) -> Vec<String> {
+    let mut parts = Vec::new();
+    if !schedule.months.wildcard {
+        parts.push(format!("BYMONTH={}", join(&schedule.months.values)));
+    }
+    let month_days: Vec<String> = schedule
+        .days_of_month
+        .iter()
+        .filter_map(|i| match i {
+            DayOfMonthItem::Day(d) => Some(d.to_string()),
+            DayOfMonthItem::LastDay => Some("-1".to_string()),
+            DayOfMonthItem::LastDayOffset(n) => Some(format!("-{}", n + 1)),
+            _ => None,
+        })
+        .collect();
+    if !month_days.is_empty() {
+        parts.push(format!("BYMONTHDAY={}", month_days.join(",")));
+    }
+    let week_days: BTreeSet<u32> = schedule
+        .days_of_week
+        .iter()
+        .filter_map(|i| match i {
+            DayOfWeekItem::Day(d) => Some(*d),
+            _ => None,
+        })
+        .collect();
+    if !week_days.is_empty() {
+        // Weeks start on Monday in RRULEs (WKST default), list them in that order.
+        let codes: Vec<&str> = (1..=7)
+            .map(|d| d % 7)
+            .filter(|d| week_days.contains(d))
+            .map(|d| WEEKDAY_CODES[d as usize])
+            .collect();
+        parts.push(format!("BYDAY={}", codes.join(",")));
+    }
+    parts
+}
+
+// Field values of an imported rule, sorted; `None` is `*`.
+type Values = Option<Vec<u32>>;
+
+const FREQUENCIES: [&str; 7] = [
+    "SECONDLY", "MINUTELY", "HOURLY", "DAILY", "WEEKLY", "MONTHLY", "YEARLY",
+];
+const SECONDLY: usize = 0;
+const MINUTELY: usize = 1;
+const HOURLY: usize = 2;
+const DAILY: usize = 3;
+const WEEKLY: usize = 4;
+const MONTHLY: usize = 5;
+const YEARLY: usize = 6;
+
+struct RRuleImporter<'a> {
+    parts: &'a HashMap<String, String>,
+    dtstart: Option<&'a DateTime>,
+}
+
+impl RRuleImporter<'_> {
+    fn cron_expressions(&self) -> Result<Vec<String>, ConversionError> {
+        for key in ["COUNT", "UNTIL", "BYYEARDAY", "BYWEEKNO"] {
+            if self.parts.contains_key(key) {
+                return Err(unsupported(format!("{} has no cron equivalent", key)));
+            }
+        }
+        let freq = FREQUENCIES
+            .iter()
+            .position(|f| Some(&f.to_string()) == self.parts.get("FREQ"))
+            .ok_or_else(|| self.invalid())?;
+        let interval = match self.parts.get("INTERVAL") {
+            Some(i) => i
+                .parse::<u32>()
+                .ok()
+                .filter(|i| *i > 0)
+                .ok_or_else(|| self.invalid())?,
+            None => 1,
+        };
+        if interval > 1 && (freq == DAILY || freq == WEEKLY) {
+            return Err(unsupported(format!(
+                "FREQ={};INTERVAL={} has no cron equivalent",
+                FREQUENCIES[freq], interval
+            )));
+        }
+
+        let seconds = self.time_field(SECONDLY, "BYSECOND", 59, freq, interval, |d| d.second)?;
+        let minutes = self.time_field(MINUTELY, "BYMINUTE", 59, freq, interval, |d| d.minute)?;
+        let hours = self.time_field(HOURLY, "BYHOUR", 23, freq, interval, |d| d.hour)?;
+        let months = self.months(freq, interval)?;
+        let years = if freq == YEARLY && interval > 1 {
+            let start = self.anchor("FREQ=YEARLY;INTERVAL")?.year;
+            Some(format!("{}/{}", start, interval))
+        } else {
+            None
+        };
+        let count = |values: &Values, size: usize| values.as_ref().map_or(size, |v| v.len());
+        let per_day = count(&seconds, 60) * count(&minutes, 60) * count(&hours, 24);
+        let days = self.days(freq, per_day)?;
+
+        // Quartz-style expression with seconds (and years) when the rule needs them,
+        // a plain five-field crontab entry otherwise.
+        let long = years.is_some() || seconds != Some(vec![0]);
+        Ok(days
+            .into_iter()
+            .map(|(mut dom, mut dow)| {
+                let mut fields = Vec::new();
+                if long {
+                    fields.push(format_values(&seconds, 0, 59, None));
+                    if dow == "*" {
+                        dow = "?".to_string();
+                    } else if dom == "*" {
+                        dom = "?".to_string();
+                    }
+                }
+                fields.push(format_values(&minutes, 0, 59, None));
+                fields.push(format_values(&hours, 0, 23, None));
+                fields.push(dom);
+                fields.push(format_values(&months, 1, 12, None));
+                fields.push(dow);
+                fields.extend(years.clone());
+                fields.join(" ")
+            })
+            .collect())
+    }
+
+    // BYxxx values, narrowed to a step when FREQ is this unit with an INTERVAL, otherwise
+    // taken from DTSTART when FREQ is coarser than the unit.
+    fn time_field(
+        &self,
+        unit: usize,
+        key: &str,
+        max: u32,
+        freq: usize,
+        interval: u32,
+        anchor: fn(&DateTime) -> u32,
+    ) -> Result<Values, ConversionError> {
+        let by = self.numbers(key, 0, max)?;
+        if freq == unit && interval > 1 {
+            let start = self.dtstart.map_or(0, anchor) % interval;
+            return Ok(Some(self.stepped(freq, start, max, interval, by)?));
+        }
+        Ok(match by {
+            Some(by) => Some(by),
+            None if freq <= unit => None,
+            None => Some(vec![self.dtstart.map_or(0, anchor)]),
+        })
+    }
+
+    fn months(&self, freq: usize, interval: u32) -> Result<Values, ConversionError> {
+        let by = self.numbers("BYMONTH", 1, 12)?;
+        if freq == MONTHLY && interval > 1 {
+            let start = (self.dtstart.map_or(1, |d| d.month) - 1) % interval + 1;
+            return Ok(Some(self.stepped(freq, start, 12, interval, by)?));
+        }
+        let day_rule = self.parts.contains_key("BYMONTHDAY") || self.parts.contains_key("BYDAY");
+        Ok(match by {
+            Some(by) => Some(by),
+            None if freq == YEARLY && !day_rule => Some(vec![self.anchor("FREQ=YEARLY")?.month]),
+            None => None,
+        })
+    }
+
+    // A step only repeats identically in cron when it divides the enclosing unit.
+    fn stepped(
+        &self,
+        freq: usize,
+        start: u32,
+        max: u32,
+        interval: u32,
+        by: Values,
+    ) -> Result<Vec<u32>, ConversionError> {
+        let size = if freq == MONTHLY { 12 } else { max + 1 };
+        if size % interval != 0 {
+            return Err(unsupported(format!(
+                "FREQ={};INTERVAL={} has no cron equivalent",
+                FREQUENCIES[freq], interval
+            )));
+        }
+        Ok((start..=max)
+            .step_by(interval as usize)
+            .filter(|v| by.as_ref().is_none_or(|by| by.contains(v)))
+            .collect())
+    }
+
+    // Day of month and day of week of each expression.
+    fn days(&self, freq: usize, per_day: usize) -> Result<Vec<(String, String)>, ConversionError> {
+        let month_days = self.parts.get("BYMONTHDAY");
+        let week_days = self.parts.get("BYDAY");
+        if month_days.is_some() && week_days.is_some() {
+            return Err(unsupported(
+                "BYMONTHDAY with BYDAY requires both to match, cron matches either".to_string(),
+            ));
+        }
+        if let Some(positions) = self.parts.get("BYSETPOS") {
+            return Ok(vec![self.set_position_days(freq, positions, per_day)?]);
+        }
+        if let Some(month_days) = month_days {
+            let mut days = Vec::new();
+            let mut lasts = Vec::new();
+            for day in month_days.split(',') {
+                match day.parse::<i32>() {
+                    Ok(-1) => lasts.push("L".to_string()),
+                    Ok(d) if (-31..=-2).contains(&d) => lasts.push(format!("L-{}", -d - 1)),
+                    Ok(d) if (1..=31).contains(&d) => days.push(d as u32),
+                    Ok(_) => {
+                        return Err(unsupported(format!(
+                            "BYMONTHDAY={} has no cron equivalent",
+                            day
+                        )))
+                    }
+                    Err(_) => return Err(self.invalid()),
+                }
+            }
+            // `L` and `L-n` cannot be part of a list in Quartz, they get an expression each.
+            days.sort_unstable();
+            let mut expressions = Vec::new();
+            if !days.is_empty() {
+                expressions.push((format_values(&Some(days), 1, 31, None), "*".to_string()));
+            }
+            expressions.extend(lasts.into_iter().map(|l| (l, "*".to_string())));
+            return Ok(expressions);
+        }
+        if let Some(week_days) = week_days {
+            let mut plain = Vec::new();
+            let mut days = Vec::new();
+            for item in week_days.split(',') {
+                let (ordinal, day) = self.week_day(item)?;
+                if ordinal.is_empty() {
+                    plain.push(day);
+                    continue;
+                }
+                if freq < MONTHLY || (freq == YEARLY && !self.parts.contains_key("BYMONTH")) {
+                    return Err(unsupported(format!(
+                        "BYDAY={} outside a month has no cron equivalent",
+                        item
+                    )));
+                }
+                let dow = match ordinal.parse::<i32>() {
+                    Ok(-1) => format!("{}L", DAY_OF_WEEK_NAMES[day as usize]),
+                    Ok(n) if (1..=5).contains(&n) => {
+                        format!("{}#{}", DAY_OF_WEEK_NAMES[day as usize], n)
+                    }
+                    _ => {
+                        return Err(unsupported(format!(
+                            "BYDAY={} has no cron equivalent",
+                            item
+                        )))
+                    }
+                };
+                days.push(("*".to_string(), dow));
+            }
+            if !plain.is_empty() {
+                plain.sort_unstable();
+                plain.dedup();
+                let dow = format_values(&Some(plain), 0, 6, Some(&DAY_OF_WEEK_NAMES));
+                days.insert(0, ("*".to_string(), dow));
+            }
+            return Ok(days);
+        }
+        Ok(vec![match freq {
+            WEEKLY => {
+                let day = self.anchor("FREQ=WEEKLY without BYDAY")?.day_of_week();
+                ("*".to_string(), DAY_OF_WEEK_NAMES[day as usize].to_string())
+            }
+            MONTHLY | YEARLY => {
+                let day = self
+                    .anchor("FREQ=MONTHLY or YEARLY without BYMONTHDAY")?
+                    .day;
+                (day.to_string(), "*".to_string())
+            }
+            _ => ("*".to_string(), "*".to_string()),
+        }])
+    }
+
+    // BYSETPOS picking the last weekday, or the nth or last of one weekday, out of a whole
+    // day's worth of occurrences at a time; the inverse of `set_position_rule`.
+    fn set_position_days(
+        &self,
+        freq: usize,
+        positions: &str,
+        per_day: usize,
+    ) -> Result<(String, String), ConversionError> {
+        let no_equivalent =
+            || unsupported(format!("BYSETPOS={} has no cron equivalent", positions));
+        let mut days = Vec::new();
+        for item in self
+            .parts
+            .get("BYDAY")
+            .ok_or_else(no_equivalent)?
+            .split(',')
+        {
+            match self.week_day(item)? {
+                ("", day) => days.push(day),
+                _ => return Err(no_equivalent()),
+            }
+        }
+        days.sort_unstable();
+        let mut positions = positions
+            .split(',')
+            .map(|p| p.parse::<i64>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| self.invalid())?;
+        positions.sort_unstable();
+
+        let n = per_day as i64;
+        let last = positions == (-n..=-1).collect::<Vec<_>>();
+        let nth = (1..=5).find(|k| positions == ((k - 1) * n + 1..=k * n).collect::<Vec<_>>());
+        match (freq, days.as_slice(), last, nth) {
+            (MONTHLY, [1, 2, 3, 4, 5], true, _) => Ok(("LW".to_string(), "*".to_string())),
+            (MONTHLY, [day], true, _) => Ok((
+                "*".to_string(),
+                format!("{}L", DAY_OF_WEEK_NAMES[*day as usize]),
+            )),
+            (MONTHLY, [day], false, Some(k)) => Ok((
+                "*".to_string(),
+                format!("{}#{}", DAY_OF_WEEK_NAMES[*day as usize], k),
+            )),
+            _ => Err(no_equivalent()),
+        }
+    }
+
+    // Splits `-1FR` into its ordinal and the day of week, 0 being Sunday.
+    fn week_day<'b>(&self, item: &'b str) -> Result<(&'b str, u32), ConversionError> {
+        if item.len() < 2 || !item.is_ascii() {
+            return Err(self.invalid());
+        }
+        let (ordinal, code) = item.split_at(item.len() - 2);
+        let day = WEEKDAY_CODES
+            .iter()
+            .position(|c| *c == code)
+            .ok_or_else(|| self.invalid())?;
+        Ok((ordinal, day as u32))
+    }
+
+    fn numbers(&self, key: &str, min: u32, max: u32) -> Result<Values, ConversionError> {
+        match self.parts.get(key) {
+            None => Ok(None),
+            Some(list) => {
+                let mut values = list
+                    .split(',')
+                    .map(|v| v.parse::<u32>().ok().filter(|v| (min..=max).contains(v)))
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(|| self.invalid())?;
+                values.sort_unstable();
+                values.dedup();
+                Ok(Some(values))
+            }
+        }
+    }
+
+    fn anchor(&self, what: &str) -> Result<&DateTime, ConversionError> {
+        self.dtstart
+            .ok_or_else(|| unsupported(format!("{} needs a DTSTART", what)))
+    }
+
+    fn invalid(&self) -> ConversionError {
+        let mut rule: Vec<String> = self
+            .parts
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect();
+        rule.sort();
+        invalid_rule(&rule.join(";"))
+    }
+}
+
+// Splits the RRULE into its parts and reads DTSTART, if present, from iCalendar content lines.
+fn parse_rrule_lines(
+    text: &str,
+) -> Result<(HashMap<String, String>, Option<DateTime>), ConversionError> {
+    let mut rule = None;
+    let mut dtstart = None;
+    let unfolded = text.replace("\r\n ", "").replace("\n ", "");
+    for line in unfolded.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        let line = line.to_uppercase();
+        if let Some(value) = line.strip_prefix("RRULE:") {
+            rule = Some(value.to_string());
+        } else if line.starts_with("DTSTART") {
+            let value = line.rsplit(':').next().unwrap_or_default();
+            dtstart = Some(parse_date_time(value).ok_or_else(|| invalid_rule(&line))?);
+        } else if !line.contains(':') {
+            rule = Some(line);
+        }
+    }
+    let rule = rule.ok_or_else(|| invalid_rule(text))?;
+    let mut parts = HashMap::new();
+    for part in rule.split(';').filter(|p| !p.is_empty()) {
+        let (key, value) = part.split_once('=').ok_or_else(|| invalid_rule(&rule))?;
+        parts.insert(key.to_string(), value.to_string());
+    }
+    if !parts.contains_key("FREQ") {
+        return Err(invalid_rule(&rule));
+    }
+    Ok((parts, dtstart))
+}
+
+// `20260105T093000`, `20260105T093000Z` or the date only `20260105`.
+fn parse_date_time(value: &str) -> Option<DateTime> {
+    let value = value.trim_end_matches('Z');
+    let (date, time) = value.split_once('T').unwrap_or((value, "000000"));
+    if date.len() != 8 || time.len() != 6 || !value.is_ascii() {
+        return None;
+    }
+    let number = |s: &str| s.parse::<u32>().ok();
+    Some(DateTime::new(
+        date[0..4].parse().ok()?,
+        number(&date[4..6])?,
+        number(&date[6..8])?,
+        number(&time[0..2])?,
+        number(&time[2..4])?,
+        number(&time[4..6])?,
+    ))
+}
+
+fn format_values(values: &Values, min: u32, max: u32, names: Option<&[&str]>) -> String {
+    match values {
+        Some(values) => schedule::format_values(values, min, max, names),
+        None => "*".to_string(),
+    }
+}
+
+fn unsupported(reason: String) -> ConversionError {
+    ConversionError::Unsupported(reason)
+}
+
+fn invalid_rule(rule: &str) -> ConversionError {
+    ConversionError::Parse(ParseException {
+        s: rule.to_string(),
+        error_offset: 0,
+    })
+}
+
+fn join<T: ToString>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn format_date_time(dt: &DateTime) -> String {
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}",
+        dt.year, dt.month, dt.day, dt.hour, dt.minute, dt.second
+    )
+}
+
+// The last second of a year in the time of DTSTART. RFC 5545 requires UNTIL in UTC when
+// DTSTART has a time zone, and in floating local time when it has none.
+fn until(year: i32, time_zone: Option<&str>) -> Result<String, ConversionError> {
+    let end = DateTime::new(year, 12, 31, 23, 59, 59);
+    let Some(name) = time_zone else {
+        return Ok(format_date_time(&end));
+    };
+    let zone =
+        tzdb::tz_by_name(name).ok_or_else(|| unsupported(format!("unknown time zone {}", name)))?;
+    let offset = |unix_time: i64| {
+        zone.find_local_time_type(unix_time)
+            .map(|local_time_type| local_time_type.ut_offset() as i64)
+            .map_err(|e| unsupported(format!("no UTC offset in {}: {}", name, e)))
+    };
+    // The offset at the local time read as UTC, corrected by the offset at the instant found.
+    let local = end.unix_timestamp();
+    let utc = local - offset(local - offset(local)?)?;
+    Ok(format!(
+        "{}Z",
+        format_date_time(&DateTime::from_unix_timestamp(utc))
+    ))
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+// Content lines longer than 75 octets are folded onto continuation lines starting with
+// a space, without splitting a UTF-8 sequence.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            octets = 1;
+        }
+        folded.push(c);
+        octets += c.len_utf8();
+    }
+    folded
+}
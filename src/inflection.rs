@@ -0,0 +1,93 @@
+//! Grammatical cases of day of week and month names, for languages such as Russian where "on
+//! Monday" and "from Monday to Friday" inflect the names differently.
+//!
+//! A locale maps where a name stands in a description to a case under `cases.day_of_week` and
+//! `cases.month`, and gives the inflected names under `inflections.<case>`. Words qualifying
+//! a day of the week, such as the ordinal of "the second Friday", may also agree with its
+//! gender from `genders` under `inflections.<case>_<gender>`. Messages counting a unit, such
+//! as "every {0}", may agree with the gender of the unit as `messages.<key>_<gender>`. Anything
+//! a locale leaves out falls back to the plain translation.
+
+/// Where a day of week or month name stands in a description.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Position {
+    /// A single value or an item of a list, as in "only on Monday".
+    Only,
+    /// The start of a range, as in "Monday through Friday".
+    From,
+    /// The end of a range.
+    To,
+    /// An occurrence in the month, as in "on the second Monday of the month".
+    Nth,
+    /// The last occurrence in the month.
+    Last,
+    /// The start of a step, as in "starting in March".
+    Starting,
+    /// An alternative to the days of the month, as in "or on Monday".
+    On,
+}
+
+impl Position {
+    fn key(self) -> &'static str {
+        match self {
+            Position::Only => "only",
+            Position::From => "from",
+            Position::To => "to",
+            Position::Nth => "nth",
+            Position::Last => "last",
+            Position::Starting => "starting",
+            Position::On => "on",
+        }
+    }
+}
+
+/// Translation of a key, `None` when the current locale lacks it.
+pub(crate) fn translate_optional(key: &str) -> Option<String> {
+    let translated = t!(key);
+    // rust-i18n answers a missing key with the key itself.
+    if translated == format!("{}.{}", rust_i18n::locale(), key) {
+        None
+    } else {
+        Some(translated)
+    }
+}
+
+// Case of a `day_of_week` or `month` name at a position, `None` for the plain name.
+fn case(kind: &str, position: Position) -> Option<String> {
+    translate_optional(&format!("cases.{}.{}", kind, position.key()))
+}
+
+/// Name of a day of the week or a month, `key` being its translation key such as `monday` or
+/// `march`, in the case its position takes. `plain` is used when the locale has no such form.
+pub(crate) fn inflect(kind: &str, key: &str, position: Position, plain: String) -> String {
+    case(kind, position)
+        .and_then(|case| translate_optional(&format!("inflections.{}.{}", case, key)))
+        .unwrap_or(plain)
+}
+
+/// Word qualifying a day of the week at a position, such as the ordinal or "last", agreeing
+/// with the case and gender of the day.
+pub(crate) fn agree(word_key: &str, day_key: &str, position: Position) -> String {
+    agree_optional(word_key, day_key, position).unwrap_or_else(|| t!(word_key))
+}
+
+/// Like [`agree`], `None` when the locale has no inflected form of the word.
+pub(crate) fn agree_optional(word_key: &str, day_key: &str, position: Position) -> Option<String> {
+    let case = case("day_of_week", position).unwrap_or_else(|| "nominative".to_string());
+    let mut forms = Vec::new();
+    if let Some(gender) = translate_optional(&format!("genders.{}", day_key)) {
+        forms.push(format!("{}_{}", case, gender));
+    }
+    forms.push(case);
+    forms
+        .iter()
+        .find_map(|form| translate_optional(&format!("inflections.{}.{}", form, word_key)))
+}
+
+/// Message counting a unit such as `minute`, in the form agreeing with the gender of the unit:
+/// French says "toutes les 5 minutes" but "tous les 2 jours".
+pub(crate) fn agree_message(key: &str, unit: &str) -> String {
+    translate_optional(&format!("genders.{}", unit))
+        .and_then(|gender| translate_optional(&format!("{}_{}", key, gender)))
+        .unwrap_or_else(|| t!(key))
+}
@@ -1,30 +1,51 @@
-#[macro_use]
-extern crate rust_i18n;
 extern crate strfmt;
 
 use string_builder::Builder;
 
-mod description_builder;
+// Message of a key in the current locale, looked up in the overrides and locales registered at
+// runtime, then in the translations compiled in from `locales`, with `%{name}` replaced by the
+// value given as `name = value`.
+macro_rules! t {
+    ($key:expr) => {
+        crate::locale::translate(&rust_i18n::locale(), $key)
+    };
+    ($key:expr, $($name:tt = $value:expr),+ $(,)?) => {{
+        let mut message = t!($key);
+        $(
+            let placeholder = format!("%{{{}}}", stringify!($name).trim_matches('"'));
+            message = message.replace(&placeholder, &$value.to_string());
+        )+
+        message
+    }};
+}
 
-rust_i18n::i18n!("locales");
+mod bidi;
+mod description_builder;
+pub mod dialect;
+pub mod discovery;
+pub mod ical;
+mod inflection;
+pub mod locale;
+mod plural;
+pub mod schedule;
 
 mod string_utils {
     pub fn not_contains_any(str: &String, chars: &[char]) -> bool {
         str.chars().all(|c| !chars.contains(&c))
     }
-
-    pub fn is_numeric(s: &str) -> bool {
-        for c in s.chars() {
-            if !c.is_numeric() {
-                return false;
-            }
-        }
-        return true;
-    }
 }
 
 mod date_time_utils {
     pub static DAYS_OF_WEEK_ARR: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+    pub static DAY_OF_WEEK_KEYS: [&str; 7] = [
+        "sunday",
+        "monday",
+        "tuesday",
+        "wednesday",
+        "thursday",
+        "friday",
+        "saturday",
+    ];
     pub static MONTHS_ARR: [&str; 12] = [
         "january",
         "february",
@@ -40,7 +61,7 @@ mod date_time_utils {
         "december",
     ];
 
-    use crate::cronparser::Options;
+    use crate::cronparser::{Clock, Options};
 
     pub fn format_time(
         hours_expression: &String,
@@ -61,51 +82,199 @@ mod date_time_utils {
         seconds_expression: &String,
         opts: &Options,
     ) -> String {
-        let mut hour: i8 = hours_expression.parse().unwrap();
-        let mut period: String = "".to_string();
+        let hour: u32 = hours_expression.parse().unwrap();
+        let minute: u32 = minutes_expression.parse().unwrap();
+        let second: Option<u32> = if seconds_expression.is_empty() {
+            None
+        } else {
+            Some(seconds_expression.parse().unwrap())
+        };
 
-        if !opts.twenty_four_hour_time {
-            period = if hour >= 12 {
-                t!("time_pm")
-            } else {
-                t!("time_am")
-            };
-            if !period.len() > 0 {
-                period = " ".to_string() + &period;
+        if let Some(pattern) = opts.time_pattern {
+            return format_time_pattern(pattern, hour, minute, second);
+        }
+        if opts.noon_and_midnight && minute == 0 && second.unwrap_or(0) == 0 {
+            match hour {
+                0 => return t!("midnight"),
+                12 => return t!("noon"),
+                _ => {}
             }
-            if hour > 12 {
-                hour -= 12;
+        }
+
+        let twenty_four_hour = is_twenty_four_hour(opts);
+        let mut time = if twenty_four_hour {
+            format!("{:02}", hour)
+        } else {
+            match hour % 12 {
+                0 => "12".to_string(),
+                hour => hour.to_string(),
             }
-            if hour == 0 {
-                hour = 12;
+        };
+        // "09" alone would not read as a time, so only the 12-hour clock drops the minutes.
+        if !(opts.hour_only && !twenty_four_hour && minute == 0 && second.is_none()) {
+            time.push_str(&format!(":{:02}", minute));
+        }
+        if let Some(second) = second {
+            time.push_str(&format!(":{:02}", second));
+        }
+        if !twenty_four_hour {
+            let period = if hour >= 12 { t!("time_pm") } else { t!("time_am") };
+            if !period.is_empty() {
+                time = with_period(time, &period, " ");
             }
         }
+        time
+    }
 
-        let minutes = minutes_expression.parse::<i8>().unwrap().to_string();
-        let mut seconds: String = "".to_string();
+    // A 12-hour time with its AM or PM after it, or before it for locales such as Japanese
+    // setting `period_position` to `before`, as in "午前9:00".
+    fn with_period(time: String, period: &str, space: &str) -> String {
+        if crate::inflection::translate_optional("period_position").as_deref() == Some("before") {
+            let space = if t!("space_between_words") == "false" { "" } else { space };
+            format!("{}{}{}", period, space, time)
+        } else {
+            format!("{}{}{}", time, space, period)
+        }
+    }
 
-        if !seconds_expression.is_empty() {
-            seconds = ":".to_string() + &seconds_expression.parse::<i8>().unwrap().to_string();
-            seconds = format!("{:0>2}", seconds);
+    /// Whether times are written in the 24-hour clock, [`Clock::Locale`] following the
+    /// `clock` of the current locale.
+    pub fn is_twenty_four_hour(opts: &Options) -> bool {
+        match opts.clock {
+            Clock::TwelveHour => false,
+            Clock::TwentyFourHour => true,
+            Clock::Locale => t!("clock") == "24h",
         }
-        let formatted_hours = if opts.twenty_four_hour_time {
-            format!("{:0>2}", hour)
+    }
+
+    // Renders a `time_pattern`. Seconds are inserted after the minutes when the pattern has
+    // no `%S`, so that times differing only by their seconds stay apart.
+    fn format_time_pattern(pattern: &str, hour: u32, minute: u32, second: Option<u32>) -> String {
+        let pattern = match second {
+            Some(_) if !pattern.contains("%S") => pattern.replacen("%M", "%M:%S", 1),
+            _ => pattern.to_string(),
+        };
+        let twelve_hour = match hour % 12 {
+            0 => 12,
+            hour => hour,
+        };
+        let mut time = String::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                time.push(c);
+                continue;
+            }
+            let mut directive = chars.next();
+            let unpadded = directive == Some('-');
+            if unpadded {
+                directive = chars.next();
+            }
+            let number = |value: u32| {
+                if unpadded {
+                    value.to_string()
+                } else {
+                    format!("{:02}", value)
+                }
+            };
+            match directive {
+                Some('H') => time.push_str(&number(hour)),
+                Some('I') => time.push_str(&number(twelve_hour)),
+                Some('M') => time.push_str(&number(minute)),
+                Some('S') => time.push_str(&number(second.unwrap_or(0))),
+                Some('p') => time.push_str(&if hour >= 12 { t!("time_pm") } else { t!("time_am") }),
+                Some('%') => time.push('%'),
+                Some(other) => {
+                    time.push('%');
+                    time.push(other);
+                }
+                None => time.push('%'),
+            }
+        }
+        time
+    }
+
+    /// Time for compact descriptions: `09:00` in 24-hour clock, otherwise `9a` or `9:30p`.
+    pub fn format_compact_time(
+        hours_expression: &String,
+        minutes_expression: &String,
+        seconds_expression: &String,
+        opts: &Options,
+    ) -> String {
+        if opts.time_pattern.is_some() || is_twenty_four_hour(opts) {
+            return format_time_secs(hours_expression, minutes_expression, seconds_expression, opts);
+        }
+        let hour: u32 = hours_expression.parse().unwrap();
+        let minute: u32 = minutes_expression.parse().unwrap();
+        let period = if hour >= 12 {
+            t!("compact.time_pm")
         } else {
-            format!("{}", hour)
+            t!("compact.time_am")
         };
-        format!(
-            "{0}:{1}{2}{3}",
-            formatted_hours,
-            format!("{:0>2}", minutes),
-            seconds,
-            period
-        )
+        let mut time = match hour % 12 {
+            0 => "12".to_string(),
+            hour => hour.to_string(),
+        };
+        if minute != 0 || !seconds_expression.is_empty() {
+            time.push_str(&format!(":{:02}", minute));
+        }
+        if !seconds_expression.is_empty() {
+            time.push_str(&format!(":{:02}", seconds_expression.parse::<u32>().unwrap()));
+        }
+        with_period(time, &period, "")
+    }
+
+    /// Abbreviated name of a day of week, 0 or 7 being Sunday.
+    pub fn get_day_of_week_abbreviation(day_of_week: usize) -> String {
+        t!(&format!("abbreviations.{}", DAY_OF_WEEK_KEYS[day_of_week % 7]))
     }
 
     pub fn get_day_of_week_name(day_of_week: usize) -> String {
         let day_str = DAYS_OF_WEEK_ARR[day_of_week % 7];
         t!(day_str)
     }
+
+    pub fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    pub fn days_in_month(year: i32, month: u32) -> u32 {
+        match month {
+            2 if is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    // Days since 1970-01-01 in the proleptic Gregorian calendar (Howard Hinnant's algorithm).
+    pub fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+        let y = if month <= 2 { year - 1 } else { year } as i64;
+        let era = if y >= 0 { y } else { y - 399 } / 400;
+        let yoe = y - era * 400;
+        let mp = (month as i64 + 9) % 12;
+        let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146097 + doe - 719468
+    }
+
+    pub fn civil_from_days(days: i64) -> (i32, u32, u32) {
+        let z = days + 719468;
+        let era = if z >= 0 { z } else { z - 146096 } / 146097;
+        let doe = z - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (yoe + era * 400 + if month <= 2 { 1 } else { 0 }) as i32;
+        (year, month, day)
+    }
+
+    // 0 = Sunday, matching DAYS_OF_WEEK_ARR.
+    pub fn day_of_week(year: i32, month: u32, day: u32) -> u32 {
+        (days_from_civil(year, month, day) + 4).rem_euclid(7) as u32
+    }
 }
 
 pub fn format_minutes(minutes_expression: &str) -> String {
@@ -123,12 +292,46 @@ pub fn format_minutes(minutes_expression: &str) -> String {
 }
 
 pub mod cronparser {
+    #[derive(Debug, Clone, Copy, PartialEq)]
     pub enum CasingTypeEnum {
         Title,
         Sentence,
         LowerCase,
     }
 
+    /// How much of an expression is spelled out.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Verbosity {
+        /// Terse text for tables and badges, such as "Mon–Fri 09:00".
+        Compact,
+        /// Leaves out the fields that are not restricted.
+        Normal,
+        /// Spells out every field, such as "every day, every month, every year".
+        Verbose,
+    }
+
+    /// Which clock times of day are written in.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Clock {
+        /// The clock usual in the current locale, such as 24-hour in Spanish.
+        Locale,
+        /// Such as "9:00 PM".
+        TwelveHour,
+        /// Such as "21:00".
+        TwentyFourHour,
+    }
+
+    /// Whose day of week and month names an expression may use besides the English ones, such
+    /// as `MON` and `JAN`, which always keep their meaning.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum NameLocale<'a> {
+        English,
+        /// Those of the locale the expression is described in, such as `LUN-VIE` in Spanish.
+        Current,
+        /// Those of a locale, such as `"pt"` for `SEG-SEX`.
+        Locale(&'a str),
+    }
+
     pub enum DescriptionTypeEnum {
         FULL,
         TIMEOFDAY,
@@ -141,31 +344,68 @@ pub mod cronparser {
         YEAR,
     }
 
-    pub struct Options {
+    pub struct Options<'a> {
         pub throw_exception_on_parse_error: bool,
         pub casing_type: CasingTypeEnum,
-        pub verbose: bool,
+        pub verbosity: Verbosity,
         pub zero_based_day_of_week: bool,
-        pub twenty_four_hour_time: bool,
+        pub clock: Clock,
+        /// Writes "noon" and "midnight" rather than "12:00 PM" and "12:00 AM".
+        pub noon_and_midnight: bool,
+        /// Leaves out the minutes of a 12-hour time on the hour, as in "9 AM".
+        pub hour_only: bool,
+        /// A pattern every time is written with, overriding `clock`, `noon_and_midnight`
+        /// and `hour_only`: `%H` and `%I` for the 24 and 12-hour clock hour, `%M`, `%S`,
+        /// `%p` for AM or PM, `%-` for no zero padding, such as "%-I.%M %p" for "9.00 PM".
+        pub time_pattern: Option<&'a str>,
         pub need_space_between_words: bool,
+        /// Whether a day matching either a restricted day of month or a restricted day of
+        /// week fires, as in Unix and Vixie cron, rather than only a day matching both.
+        pub match_any_day: bool,
+        /// The most times of day listed one by one, as in "At 9:00 AM, 9:30 AM, 5:00 PM and
+        /// 5:30 PM", when seconds, minutes and hours are lists of single values.
+        pub max_listed_times: usize,
+        /// The most characters of a [`Verbosity::Compact`] description. Longer descriptions
+        /// are abbreviated, then lose the words the locale gives under `optional_words`, such
+        /// as "on day", and as a last resort are cut at a word boundary with an ellipsis.
+        pub max_length: Option<usize>,
+        /// Makes [`Verbosity::Compact`] descriptions shorter still for narrow columns, such as
+        /// "Weekdays 9a–5p, every 15 min" rather than "Mon–Fri every 15 minutes between
+        /// 09:00 and 17:45".
+        pub abbreviated: bool,
+        /// Describes days of the month and occurrences of a day of the week with ordinals,
+        /// such as "on the 1st and 15th of the month" rather than "on day 1 and 15 of the month".
+        pub ordinal_days: bool,
+        /// Whose day of week and month names expressions may use, full or abbreviated as in
+        /// descriptions and whatever their case and accents.
+        pub name_locale: NameLocale<'a>,
     }
 
-    impl Options {
-        pub fn options() -> Options {
+    impl Options<'_> {
+        pub fn options() -> Options<'static> {
             return Options {
                 throw_exception_on_parse_error: true,
                 casing_type: CasingTypeEnum::Sentence,
-                verbose: false,
+                verbosity: Verbosity::Normal,
                 zero_based_day_of_week: true,
-                twenty_four_hour_time: false,
+                clock: Clock::Locale,
+                noon_and_midnight: false,
+                hour_only: false,
+                time_pattern: None,
                 need_space_between_words: true,
+                match_any_day: true,
+                max_listed_times: 8,
+                max_length: None,
+                abbreviated: false,
+                ordinal_days: false,
+                name_locale: NameLocale::English,
             };
         }
 
-        pub fn twenty_four_hour() -> Options {
+        pub fn twenty_four_hour() -> Options<'static> {
             let opts = Options::options();
             let opts2 = Options {
-                twenty_four_hour_time: true,
+                clock: Clock::TwentyFourHour,
                 ..opts
             };
             return opts2;
@@ -173,19 +413,22 @@ pub mod cronparser {
     }
 
     pub mod cron_expression_descriptor {
-        use lazy_static::lazy_static;
         use std::collections::HashMap;
         use string_builder::Builder;
 
-        use crate::cronparser::{CasingTypeEnum, DescriptionTypeEnum, Options};
-        use crate::date_time_utils::{format_time, format_time_secs};
+        use crate::cronparser::{CasingTypeEnum, Clock, DescriptionTypeEnum, Options, Verbosity};
+        use crate::date_time_utils::{format_compact_time, format_time, format_time_secs};
         use crate::description_builder::DescriptionBuilder;
         use crate::description_builder::{
             DayOfMonthDescriptionBuilder, DayOfWeekDescriptionBuilder, HoursDescriptionBuilder,
             MinutesDescriptionBuilder, MonthDescriptionBuilder, SecondsDescriptionBuilder,
             YearDescriptionBuilder,
         };
-        use crate::{cronparser, string_utils};
+        use crate::description_builder::{
+            count, join_list, need_space, separator, without_separator,
+        };
+        use crate::schedule::{self, DayOfMonthItem};
+        use crate::{bidi, cronparser, plural, string_utils};
 
         const SPECIAL_CHARACTERS: [char; 4] = ['/', '-', ',', '*'];
 
@@ -195,7 +438,7 @@ pub mod cronparser {
             pub error_offset: u8,
         }
 
-        mod expression_parser {
+        pub(crate) mod expression_parser {
             /* Cron reference
              ┌───────────── minute (0 - 59)
              │ ┌───────────── hour (0 - 23)
@@ -212,7 +455,8 @@ pub mod cronparser {
 
             use crate::cronparser::cron_expression_descriptor::ParseException;
             use crate::cronparser::Options;
-            use crate::string_utils;
+            use crate::date_time_utils::{DAY_OF_WEEK_KEYS, MONTHS_ARR};
+            use crate::schedule;
             use regex::Regex;
 
             pub fn parse(
@@ -260,17 +504,54 @@ pub mod cronparser {
                     }
 
                     let normalized_expr = normalise_expression(parsed, options);
+                    validate_fields(expression, &normalized_expr, options)?;
                     Ok(normalized_expr)
                 }
             }
 
+            // Rejects malformed `L`, `W` and `#` days such as `L-40` or `MON#6`, days out of
+            // range such as `32` or `8`, and year ranges running backwards such as `2030-2025`.
+            fn validate_fields(
+                expression: &str,
+                normalised: &[String],
+                options: &Options,
+            ) -> Result<(), ParseException> {
+                let error = |error_offset| ParseException {
+                    s: expression.to_string(),
+                    error_offset,
+                };
+                for item in normalised[3].split(',') {
+                    let valid = if item.contains(['L', 'W']) {
+                        schedule::parse_day_of_month_item(item).is_some()
+                    } else {
+                        schedule::parse_item(item, 1, 31).is_some()
+                    };
+                    if !valid {
+                        return Err(error(3));
+                    }
+                }
+                for item in normalised[5].split(',') {
+                    let valid = if item.contains(['L', '#']) {
+                        schedule::parse_day_of_week_item(item, options.zero_based_day_of_week)
+                            .is_some()
+                    } else {
+                        let first = schedule::first_day_of_week(options.zero_based_day_of_week);
+                        schedule::parse_item(item, first, 7).is_some()
+                    };
+                    if !valid {
+                        return Err(error(5));
+                    }
+                }
+                if normalised[6]
+                    .split(',')
+                    .any(|item| schedule::wrap_around_range(item).is_some())
+                {
+                    return Err(error(6));
+                }
+                Ok(())
+            }
+
             fn normalise_expression(expression_parts: Vec<&str>, options: &Options) -> Vec<String> {
-                static DAYS_OF_WEEK_ARR: [&str; 7] =
-                    ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
-                static MONTHS_ARR: [&str; 12] = [
-                    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV",
-                    "DEC",
-                ];
                 let mut normalised: Vec<String> = vec!["".to_string(); 7];
 
                 (0..expression_parts.len()).for_each(|i| {
@@ -280,17 +561,34 @@ pub mod cronparser {
                 normalised[3] = normalised[3].replace("?", "*");
                 normalised[5] = normalised[5].replace("?", "*");
 
-                (0..=2).for_each(|i| {
-                    normalised[i] = if normalised[i].starts_with("0/") {
-                        normalised[i].replace("0/", "*/")
-                    } else {
-                        normalised[i].to_string()
-                    }
-                });
+                // convert SUN-SAT format to 0-6 format (1-7 when not zero based) and JAN-DEC
+                // format to 1-12 format, whatever their case and whether abbreviated or not
+                let first_day_of_week = if options.zero_based_day_of_week { 0 } else { 1 };
+                normalised[5] = schedule::replace_localized_names(
+                    &normalised[5],
+                    &schedule::DAY_OF_WEEK_NAMES,
+                    &schedule::localized_names(&DAY_OF_WEEK_KEYS, options),
+                    first_day_of_week,
+                );
+                normalised[4] = schedule::replace_localized_names(
+                    &normalised[4],
+                    &schedule::MONTH_NAMES,
+                    &schedule::localized_names(&MONTHS_ARR, options),
+                    1,
+                );
+
+                // a range of seconds, minutes or days of the month ending before its start wraps
+                // around, and is spelt out so as not to be described as running backwards
+                normalised[0] = schedule::unwrap_ranges(&normalised[0], 0, 59);
+                normalised[1] = schedule::unwrap_ranges(&normalised[1], 0, 59);
+                normalised[3] = schedule::unwrap_ranges(&normalised[3], 1, 31);
 
-                (3..=5).for_each(|i| {
-                    normalised[i] = if normalised[i].starts_with("1/") {
-                        normalised[i].replace("1/", "*/")
+                // a step from the lowest value of the field is a step from *
+                let first_day_of_week_step = format!("{}/", first_day_of_week);
+                let lowest_steps = ["0/", "0/", "0/", "1/", "1/", &first_day_of_week_step];
+                (0..=5).for_each(|i| {
+                    normalised[i] = if normalised[i].starts_with(lowest_steps[i]) {
+                        normalised[i].replacen(lowest_steps[i], "*/", 1)
                     } else {
                         normalised[i].to_string()
                     }
@@ -302,22 +600,6 @@ pub mod cronparser {
                     }
                 }
                 // println!("normalised after replacing */1: {:?}", normalised);
-                // convert SUN-SAT format to 0-6 format
-                if !string_utils::is_numeric(&normalised[5]) {
-                    for i in 0..=6 {
-                        normalised[5] =
-                            normalised[5].replace(DAYS_OF_WEEK_ARR[i], i.to_string().as_str());
-                    }
-                }
-
-                // convert JAN-DEC format to 1-12 format
-                if !string_utils::is_numeric(&normalised[4]) {
-                    for i in 1..12 {
-                        normalised[4] =
-                            normalised[4].replace(MONTHS_ARR[i - 1], i.to_string().as_str());
-                    }
-                }
-
                 // convert 0 second to (empty)
                 if "0" == normalised[0] {
                     normalised[0] = "".to_string();
@@ -343,7 +625,8 @@ pub mod cronparser {
             options: &Options,
             locale: &str,
         ) -> Result<String, ParseException> {
-            rust_i18n::set_locale(&locale);
+            // "pt-BR" is described in Portuguese, an unknown locale in English.
+            rust_i18n::set_locale(&crate::locale::resolve(locale));
             let expression_parsed = expression_parser::parse(expression, options);
             match expression_parsed {
                 Ok(expression_parts) => {
@@ -376,7 +659,7 @@ pub mod cronparser {
                             get_year_description(&expression_parts, options)
                         }
                     };
-                    Ok(description_res)
+                    Ok(bidi::isolate(description_res))
                 }
                 Err(pe) => Err(pe),
             }
@@ -384,42 +667,278 @@ pub mod cronparser {
 
         // From the C# code, not Java.
         fn get_full_description(expression_parts: &Vec<String>, options: &Options) -> String {
+            if options.verbosity == Verbosity::Compact {
+                return get_compact_description(expression_parts, options);
+            }
             let time_segment = get_time_of_day_description(&expression_parts, options);
             let day_of_month_desc = get_day_of_month_description(&expression_parts, options);
-            let month_desc = get_month_description(&expression_parts, options);
-            let day_of_week_desc = get_day_of_week_description(&expression_parts, options);
-            let year_desc = get_year_description(&expression_parts, options);
+            let verbose = options.verbosity == Verbosity::Verbose;
+            let month_desc = if verbose && expression_parts[4] == "*" {
+                separator() + &t!("messages.every_month")
+            } else {
+                get_month_description(&expression_parts, options)
+            };
+            let day_of_week_desc = if is_spelled_out(&expression_parts[5], false, options) {
+                get_day_of_week_description(&expression_parts, options)
+            } else {
+                String::new()
+            };
+            let year_desc = match expression_parts[6].as_str() {
+                "" | "*" if verbose => separator() + &t!("messages.every_year"),
+                "*" => String::new(),
+                _ => get_year_description(&expression_parts, options),
+            };
             let week_or_month_desc = if "*" == &expression_parts[3] {
                 day_of_week_desc
-            } else {
+            } else if "*" == &expression_parts[5] {
                 day_of_month_desc
+            } else if schedule::matches_any_day(&expression_parts[3], &expression_parts[5], options) {
+                let builder = DayOfWeekDescriptionBuilder { options };
+                let alternative = builder.get_alternative_description(&expression_parts[5]);
+                day_of_month_desc + &separator() + &alternative
+            } else {
+                day_of_month_desc + &day_of_week_desc
+            };
+            // Locales such as Japanese put the days, months and years before the time.
+            let order = crate::inflection::translate_optional("segment_order").unwrap_or_default();
+            let mut segments = [
+                ("time", time_segment),
+                ("days", week_or_month_desc),
+                ("month", month_desc),
+                ("year", year_desc),
+            ];
+            segments.sort_by_key(|(name, _)| order.split(' ').position(|n| n == *name));
+            let description = segments
+                .iter()
+                .map(|(_, segment)| without_separator(segment))
+                .filter(|segment| !segment.is_empty())
+                .collect::<Vec<_>>()
+                .join(&separator());
+            transform_case(&description, options)
+        }
+
+        // Unrestricted fields are only described when verbose, or when nothing else is.
+        fn is_spelled_out(expression: &str, nothing_before: bool, options: &Options) -> bool {
+            expression != "*" || options.verbosity == Verbosity::Verbose || nothing_before
+        }
+
+        fn get_compact_description(expression_parts: &Vec<String>, options: &Options) -> String {
+            let description = |segments: Vec<String>| {
+                transform_case(&segments.join(&separator()), options)
             };
-            let desc1 = format!(
-                "{0}{1}{2}{3}",
-                time_segment, week_or_month_desc, month_desc, year_desc
+            let fits = |description: &String| {
+                let length = description.chars().count();
+                options.max_length.is_none_or(|max_length| length <= max_length)
+            };
+            let mut shortest = description(get_compact_segments(expression_parts, options));
+            if fits(&shortest) {
+                return shortest;
+            }
+            // Too long: abbreviate, then leave out the words the locale marks as optional,
+            // and only as a last resort cut at a word boundary.
+            let abbreviated = Options {
+                abbreviated: true,
+                ..*options
+            };
+            let segments = get_compact_segments(expression_parts, &abbreviated);
+            if !options.abbreviated {
+                shortest = description(segments.clone());
+                if fits(&shortest) {
+                    return shortest;
+                }
+            }
+            let terse = description(segments.iter().map(|s| without_optional_words(s)).collect());
+            if terse.chars().count() < shortest.chars().count() {
+                shortest = terse;
+            }
+            if fits(&shortest) {
+                return shortest;
+            }
+            truncate(&shortest, options.max_length.unwrap_or_default())
+        }
+
+        // A segment without the words its locale gives under `optional_words` as it may start
+        // or end with, "on the second Mon of the month" being "second Mon".
+        fn without_optional_words(segment: &str) -> String {
+            let words = |key: &str| {
+                crate::inflection::translate_optional(key)
+                    .map(|words| words.split('|').map(str::to_string).collect::<Vec<_>>())
+                    .unwrap_or_default()
+            };
+            let mut segment = segment.to_string();
+            if let Some(prefix) = words("optional_words.prefixes")
+                .iter()
+                .find(|prefix| segment.starts_with(prefix.as_str()))
+            {
+                segment = segment[prefix.len()..].to_string();
+            }
+            if let Some(suffix) = words("optional_words.suffixes")
+                .iter()
+                .find(|suffix| segment.ends_with(suffix.as_str()))
+            {
+                segment.truncate(segment.len() - suffix.len());
+            }
+            segment
+        }
+
+        // A description cut at the last word boundary leaving room for an ellipsis.
+        fn truncate(description: &str, max_length: usize) -> String {
+            let mut cut = 0;
+            for (i, c) in description.char_indices() {
+                if c == ' ' && description[..i].chars().count() < max_length {
+                    cut = i;
+                }
+            }
+            if cut == 0 {
+                return description.chars().take(max_length.saturating_sub(1)).collect::<String>() + "…";
+            }
+            description[..cut].trim_end_matches([',', ' ']).to_string() + "…"
+        }
+
+        // Days, time, days of the month, months and years, each as short as it can be put.
+        fn get_compact_segments(expression_parts: &Vec<String>, options: &Options) -> Vec<String> {
+            let trimmed = |description: String| without_separator(&description);
+            let space = if need_space(options) { " " } else { "" };
+            // Times are in 24-hour clock unless abbreviated, and listed however many there are.
+            let time_options = Options {
+                clock: if options.abbreviated { options.clock } else { Clock::TwentyFourHour },
+                max_listed_times: usize::MAX,
+                verbosity: Verbosity::Normal,
+                ..*options
+            };
+            let time = get_compact_time_description(expression_parts, &time_options)
+                .unwrap_or_else(|| get_time_of_day_description(expression_parts, &time_options));
+
+            let day_of_week_builder = DayOfWeekDescriptionBuilder { options };
+            // A day matching either restricted field fires, so the days of the week go after
+            // the days of the month as an alternative, as in "day 1, 15 or Mon".
+            let either_day =
+                schedule::matches_any_day(&expression_parts[3], &expression_parts[5], options);
+            let days_of_week = if options.abbreviated && !either_day {
+                day_of_week_builder.get_compact_days_description(&expression_parts[5])
+            } else {
+                day_of_week_builder.get_compact_description(&expression_parts[5])
+            };
+
+            // Abbreviated days go before the time, "Mon–Fri 09:00", anything longer after it.
+            let mut segments = match &days_of_week {
+                _ if either_day => vec![time],
+                Some(days) if !days.is_empty() => vec![format!("{}{}{}", days, space, time)],
+                Some(_) => vec![time],
+                None => vec![
+                    time,
+                    trimmed(get_day_of_week_description(expression_parts, options)),
+                ],
+            };
+            if expression_parts[3] != "*" {
+                let builder = DayOfMonthDescriptionBuilder { options };
+                let days_of_month = builder
+                    .get_compact_description(&expression_parts[3])
+                    .filter(|_| options.abbreviated)
+                    .map(|days| {
+                        let mut vars = HashMap::new();
+                        vars.insert("0".to_string(), days);
+                        strfmt::strfmt(&t!("compact.day_x"), &vars).unwrap()
+                    })
+                    .unwrap_or_else(|| {
+                        trimmed(get_day_of_month_description(expression_parts, options))
+                    });
+                if either_day {
+                    let alternative = match days_of_week {
+                        Some(days) => {
+                            let mut vars = HashMap::new();
+                            vars.insert("0".to_string(), days);
+                            strfmt::strfmt(&t!("messages.or_x"), &vars).unwrap()
+                        }
+                        None => day_of_week_builder.get_alternative_description(&expression_parts[5]),
+                    };
+                    segments.push(format!("{}{}{}", days_of_month, space, alternative));
+                } else {
+                    segments.push(days_of_month);
+                }
+            }
+            let month_builder = MonthDescriptionBuilder { options };
+            segments.push(
+                month_builder
+                    .get_compact_description(&expression_parts[4])
+                    .unwrap_or_else(|| trimmed(get_month_description(expression_parts, options))),
+            );
+            let year_builder = YearDescriptionBuilder { options };
+            segments.push(
+                year_builder
+                    .get_compact_description(&expression_parts[6])
+                    .unwrap_or_else(|| trimmed(get_year_description(expression_parts, options))),
             );
-            // eprintln!("time: \"{}\"; day_of_month: \"{}\"; month: \"{}\"; year: \"{}\"",
-            //           time_segment, week_or_month_desc, month_desc, year_desc);
-            // println!("before verbosity: {}", desc1);
-            let desc2 = transform_verbosity(desc1, options);
-            transform_case(&desc2, options)
-        }
-
-        fn transform_verbosity(description: String, options: &Options) -> String {
-            let mut desc_temp = description.clone();
-            if !options.verbose {
-                desc_temp =
-                    desc_temp.replace(&t!("messages.every_1_minute"), &t!("messages.every_minute"));
-                desc_temp =
-                    desc_temp.replace(&t!("messages.every_1_hour"), &t!("messages.every_hour"));
-                desc_temp =
-                    desc_temp.replace(&t!("messages.every_1_day"), &t!("messages.every_day"));
-                desc_temp = desc_temp.replace(&format!(", {}", &t!("messages.every_minute")), "");
-                desc_temp = desc_temp.replace(&format!(", {}", &t!("messages.every_hour")), "");
-                desc_temp = desc_temp.replace(&format!(", {}", &t!("messages.every_day")), "");
-                desc_temp = desc_temp.replace(&format!(", {}", &t!("messages.every_year")), "");
-            }
-            desc_temp
+            segments.retain(|s| !s.is_empty());
+            segments
+        }
+
+        // Plain times such as "09:00, 17:30", or when abbreviated "9a, 5:30p" and a window of
+        // hours and how often it fires within it such as "9a–5p, every 15 min". `None` when the
+        // time fields are too involved.
+        fn get_compact_time_description(
+            expression_parts: &[String],
+            options: &Options,
+        ) -> Option<String> {
+            let (seconds, minutes, hours) =
+                (&expression_parts[0], &expression_parts[1], &expression_parts[2]);
+            if !options.abbreviated {
+                return get_listed_times(seconds, minutes, hours, options, format_time_secs)
+                    .map(|times| times.join(&separator()));
+            }
+            if let Some(times) =
+                get_listed_times(seconds, minutes, hours, options, format_compact_time)
+            {
+                return Some(times.join(&separator()));
+            }
+            let every = |key: &str, step: &str| {
+                let mut vars = HashMap::new();
+                vars.insert("0".to_string(), step.to_string());
+                strfmt::strfmt(&t!(key), &vars).unwrap()
+            };
+            let step_of = |expression: &str| match expression.split_once('/') {
+                Some((range, step)) => (range.to_string(), Some(step.to_string())),
+                None => (expression.to_string(), None),
+            };
+            let mut pieces = Vec::new();
+
+            let (hour_range, hour_step) = step_of(hours);
+            if hour_range != "*" && hour_step.as_ref().is_some_and(|_| !hour_range.contains('-')) {
+                return None;
+            }
+            let hours_builder = HoursDescriptionBuilder { options };
+            let hour_window = hours_builder.get_compact_description(&hour_range)?;
+            if !hour_window.is_empty() {
+                pieces.push(hour_window);
+            }
+            if let Some(step) = &hour_step {
+                pieces.push(every("compact.every_x_hours", step));
+            }
+
+            let (minute_range, minute_step) = step_of(minutes);
+            match (minute_range.as_str(), &minute_step) {
+                ("*" | "0", Some(step)) => pieces.push(every("compact.every_x_minutes", step)),
+                ("*", None) if seconds.is_empty() => pieces.push(t!("compact.every_minute")),
+                ("*", None) => {}
+                ("0", None) if hour_step.is_none() && hours.contains(['-', ',']) => {
+                    pieces.push(t!("compact.hourly"))
+                }
+                ("0", None) => {}
+                (_, None) => {
+                    let minutes_builder = MinutesDescriptionBuilder { options };
+                    pieces.push(minutes_builder.get_compact_description(&minute_range)?);
+                }
+                _ => return None,
+            }
+
+            let (second_range, second_step) = step_of(seconds);
+            match (second_range.as_str(), &second_step) {
+                ("", None) => {}
+                ("*" | "0", Some(step)) => pieces.push(every("compact.every_x_seconds", step)),
+                ("*", None) => pieces.push(t!("compact.every_second")),
+                _ => return None,
+            }
+            Some(pieces.join(&separator()))
         }
 
         fn transform_case(description: &str, options: &Options) -> String {
@@ -441,7 +960,7 @@ pub mod cronparser {
             let builder = YearDescriptionBuilder { options };
             builder.get_segment_description(
                 &expression_parts[6],
-                format!(", {}", t!("messages.every_year")),
+                separator() + &t!("messages.every_year"),
             )
         }
 
@@ -451,10 +970,14 @@ pub mod cronparser {
         ) -> String {
             let builder = DayOfWeekDescriptionBuilder { options };
             // println!("in get_day_of_week_description, expr: {}", &expression_parts[5]);
-            builder.get_segment_description(
-                &expression_parts[5],
-                format!(", {}", t!("messages.every_day")),
-            )
+            builder
+                .get_list_with_occurrences_description(&expression_parts[5])
+                .unwrap_or_else(|| {
+                    builder.get_segment_description(
+                        &expression_parts[5],
+                        separator() + &t!("messages.every_day"),
+                    )
+                })
         }
 
         fn get_minutes_description(expression_parts: &Vec<String>, options: &Options) -> String {
@@ -468,8 +991,38 @@ pub mod cronparser {
         }
 
         fn get_hours_description(expression_parts: &Vec<String>, options: &Options) -> String {
+            use strfmt::strfmt;
             let builder = HoursDescriptionBuilder { options };
-            builder.get_segment_description(&expression_parts[2], t!("messages.every_hour"))
+            let hours = &expression_parts[2];
+            let wrap_around =
+                schedule::wrap_around_range(hours).filter(|_| !hours.contains(['/', ',']));
+            if let Some((start, end)) = wrap_around {
+                let time = |hour: u32| builder.get_single_item_description(&hour.to_string());
+                let between = |format: String, from: u32, to: u32| {
+                    let mut vars = HashMap::new();
+                    vars.insert("0".to_string(), time(from));
+                    vars.insert("1".to_string(), time(to));
+                    strfmt(&format, &vars).unwrap()
+                };
+                if expression_parts[3] == "*" && expression_parts[5] == "*" {
+                    return between(t!("messages.between_x_and_y_overnight"), start, end);
+                }
+                // With restricted days the hours after midnight belong to the days they
+                // fall on, not to the day before.
+                let parts: Vec<String> = [(0, end), (start, 23)]
+                    .into_iter()
+                    .map(|(from, to)| {
+                        if from == to {
+                            let vars = HashMap::from([("0".to_string(), time(from))]);
+                            strfmt(&t!("messages.at_x"), &vars).unwrap()
+                        } else {
+                            between(t!("messages.between_x_and_y"), from, to)
+                        }
+                    })
+                    .collect();
+                return join_list(&parts, need_space(options));
+            }
+            builder.get_segment_description(hours, t!("messages.every_hour"))
         }
 
         fn get_month_description(expression_parts: &Vec<String>, options: &Options) -> String {
@@ -481,37 +1034,66 @@ pub mod cronparser {
             expression_parts: &Vec<String>,
             options: &Options,
         ) -> String {
-            use regex::Regex;
-            use strfmt::strfmt;
             let exp = expression_parts[3].replace("?", "*");
-            let description = if "L" == exp {
-                format!(", {}", t!("messages.on_the_last_day_of_the_month"))
-            } else if "WL" == exp || "LW" == exp {
-                format!(", {}", t!("messages.on_the_last_weekday_of_the_month"))
-            } else {
-                lazy_static! {
-                    static ref DOM_RE: Regex = Regex::new(r"(\dW)|(W\d)").unwrap();
+            if let Some(description) = get_special_day_of_month_description(&exp, options) {
+                return separator() + &description;
+            }
+            let builder = DayOfMonthDescriptionBuilder { options };
+            let items: Vec<&str> = exp.split(',').collect();
+            let specials: Vec<String> = items
+                .iter()
+                .filter_map(|item| get_special_day_of_month_description(item, options))
+                .collect();
+            if specials.is_empty() {
+                return builder
+                    .get_segment_description(&exp, separator() + &t!("messages.every_day"));
+            }
+            // A list with `L` or `W` items, such as `L,15`: the plain days together, then each
+            // special day as it is described on its own.
+            let plain: Vec<&str> = items
+                .into_iter()
+                .filter(|item| get_special_day_of_month_description(item, options).is_none())
+                .collect();
+            let mut descriptions = Vec::new();
+            if !plain.is_empty() {
+                let description = builder.get_segment_description(&plain.join(","), String::new());
+                descriptions.push(without_separator(&description));
+            }
+            descriptions.extend(specials);
+            separator() + &join_list(&descriptions, need_space(options))
+        }
+
+        // A last day, last weekday, day before the last or weekday nearest a day of the
+        // month, such as "on the last day of the month". `None` for any other item.
+        fn get_special_day_of_month_description(item: &str, options: &Options) -> Option<String> {
+            use strfmt::strfmt;
+            let description = match schedule::parse_day_of_month_item(item)? {
+                DayOfMonthItem::LastDay => t!("messages.on_the_last_day_of_the_month"),
+                DayOfMonthItem::LastWeekday => t!("messages.on_the_last_weekday_of_the_month"),
+                DayOfMonthItem::LastDayOffset(offset) => {
+                    let mut vars = HashMap::new();
+                    vars.insert("0".to_string(), offset.to_string());
+                    let fmt_str = t!("messages.days_before_the_last_day_of_the_month")
+                        .replace("{1}", &plural::unit("day", &offset.to_string()));
+                    strfmt(&fmt_str, &vars).unwrap()
                 }
-                if DOM_RE.is_match(&exp) {
-                    let capt = DOM_RE.captures_iter(&exp).next().unwrap();
-                    let no_w = capt[0].replace("W", "");
-                    let day_number = no_w.parse::<u8>().unwrap();
+                DayOfMonthItem::NearestWeekday(day_number) => {
+                    let mut vars = HashMap::new();
+                    vars.insert("0".to_string(), day_number.to_string());
                     let day_string = if day_number == 1 {
                         t!("messages.first_weekday")
+                    } else if options.ordinal_days {
+                        vars.insert("0".to_string(), plural::ordinal(day_number));
+                        strfmt(&t!("messages.weekday_nearest_the_x"), &vars).unwrap()
                     } else {
-                        t!("messages.weekday_nearest_day", 0 = &no_w)
+                        strfmt(&t!("messages.weekday_nearest_day"), &vars).unwrap()
                     };
-                    let fmt_str = format!(", {}", t!("messages.on_the_of_the_month"));
-                    let mut vars = HashMap::new();
                     vars.insert("0".to_string(), day_string);
-                    strfmt(&fmt_str, &vars).unwrap()
-                } else {
-                    let builder = DayOfMonthDescriptionBuilder { options };
-                    // eprintln!("in get_day_of_month_description, exp: {}", exp);
-                    builder.get_segment_description(&exp, format!(", {}", t!("messages.every_day")))
+                    strfmt(&t!("messages.on_the_of_the_month"), &vars).unwrap()
                 }
+                DayOfMonthItem::Day(_) => return None,
             };
-            description
+            Some(description)
         }
 
         fn get_time_of_day_description(
@@ -523,6 +1105,15 @@ pub mod cronparser {
             let hours_expression = &expression_parts[2];
 
             let mut description = Builder::default();
+            // "At " before the time, nothing for locales without such a word.
+            let at = || {
+                let at = t!("at");
+                if at.is_empty() || !need_space(options) {
+                    at
+                } else {
+                    at + " "
+                }
+            };
 
             if minutes_expression
                 .chars()
@@ -534,50 +1125,74 @@ pub mod cronparser {
                     .chars()
                     .all(|c| !SPECIAL_CHARACTERS.contains(&c))
             {
-                description.append(t!("at"));
-                if options.need_space_between_words {
-                    description.append(" ");
-                }
+                description.append(at());
                 description.append(format_time_secs(
                     hours_expression,
                     minutes_expression,
                     seconds_expression,
                     options,
                 ));
+            } else if let Some(times) = get_listed_times(
+                seconds_expression,
+                minutes_expression,
+                hours_expression,
+                options,
+                format_time_secs,
+            ) {
+                description.append(at());
+                description.append(join_list(&times, need_space(options)));
+            } else if let Some(window) = get_stepped_window_description(
+                seconds_expression,
+                minutes_expression,
+                hours_expression,
+                options,
+            ) {
+                description.append(window);
             } else if minutes_expression.contains("-")
-                && !minutes_expression.contains("/")
-                && string_utils::not_contains_any(hours_expression, &SPECIAL_CHARACTERS)
+                && !minutes_expression.contains(['/', ','])
+                && hours_expression
+                    .split(',')
+                    .all(|h| h.parse::<u32>().is_ok())
             {
-                let mut minute_parts = minutes_expression.split("-");
-                let msg0 = format_time(
-                    hours_expression,
-                    &minute_parts.next().unwrap().to_string(),
-                    options,
-                );
-                let msg1 = format_time(
-                    hours_expression,
-                    &minute_parts.next().unwrap().to_string(),
-                    options,
-                );
-                description.append(t!("messages.every_minute_between", 0 = &msg0, 1 = &msg1));
+                let (first_minute, last_minute) = minutes_expression.split_once('-').unwrap();
+                let windows: Vec<(String, String)> = hours_expression
+                    .split(',')
+                    .map(|hour| {
+                        (
+                            format_time(&hour.to_string(), &first_minute.to_string(), options),
+                            format_time(&hour.to_string(), &last_minute.to_string(), options),
+                        )
+                    })
+                    .collect();
+                if let [(msg0, msg1)] = windows.as_slice() {
+                    description.append(t!("messages.every_minute_between", 0 = msg0, 1 = msg1));
+                } else {
+                    let betweens: Vec<String> = windows
+                        .iter()
+                        .map(|(msg0, msg1)| {
+                            let mut vars = HashMap::new();
+                            vars.insert("0".to_string(), msg0.clone());
+                            vars.insert("1".to_string(), msg1.clone());
+                            strfmt::strfmt(&t!("messages.between_x_and_y"), &vars).unwrap()
+                        })
+                        .collect();
+                    let mut vars = HashMap::new();
+                    vars.insert(
+                        "0".to_string(),
+                        join_list(&betweens, need_space(options)),
+                    );
+                    description
+                        .append(strfmt::strfmt(&t!("messages.every_minute_x"), &vars).unwrap());
+                }
             } else if hours_expression.contains(",")
                 && string_utils::not_contains_any(minutes_expression, &SPECIAL_CHARACTERS)
             {
-                let hour_parts: Vec<_> = hours_expression.split(",").collect();
-                let hpsz = hour_parts.len();
-                description.append(t!("at"));
-
-                for (i, hp) in hour_parts.iter().enumerate() {
-                    description.append(" ");
-                    description.append(format_time(&hp.to_string(), minutes_expression, options));
-                    if i < hpsz - 2 {
-                        description.append(",");
-                    }
-                    if i == hpsz - 2 {
-                        description.append(" ");
-                        description.append(t!("and"));
-                    }
-                }
+                let times: Vec<String> = hours_expression
+                    .split(",")
+                    .map(|hour| format_time(&hour.to_string(), minutes_expression, options))
+                    .collect();
+                description.append(at());
+                description.append(join_list(&times, need_space(options)));
             } else {
                 let seconds_description = get_seconds_description(expression_parts, options);
                 let minutes_description = get_minutes_description(expression_parts, options);
@@ -586,18 +1201,118 @@ pub mod cronparser {
                 // println!("seconds_description: {} minutes_description: {}, hours_description: {}",
                 //   seconds_description, minutes_description, hours_description);
                 description.append(seconds_description);
-                if description.len() > 0 && !minutes_description.is_empty() {
-                    description.append(", ");
+                if is_spelled_out(minutes_expression, description.len() == 0, options) {
+                    if description.len() > 0 && !minutes_description.is_empty() {
+                        description.append(separator());
+                    }
+                    description.append(minutes_description);
                 }
-                description.append(minutes_description);
-                if description.len() > 0 && !hours_description.is_empty() {
-                    description.append(", ");
+                if is_spelled_out(hours_expression, description.len() == 0, options) {
+                    if description.len() > 0 && !hours_description.is_empty() {
+                        description.append(separator());
+                    }
+                    description.append(hours_description);
                 }
-                description.append(hours_description);
             }
             description.string().unwrap()
         }
 
+        // A step of minutes, or of seconds within minutes, inside an hour or a range of hours,
+        // described with the first and the real last time it fires: `*/5 9-17` ends at 5:55 PM.
+        fn get_stepped_window_description(
+            seconds_expression: &str,
+            minutes_expression: &str,
+            hours_expression: &str,
+            options: &Options,
+        ) -> Option<String> {
+            let (first_hour, last_hour) = match hours_expression.split_once('-') {
+                Some((start, end)) => (start.parse::<u32>().ok()?, end.parse::<u32>().ok()?),
+                None => {
+                    let hour = hours_expression.parse::<u32>().ok()?;
+                    (hour, hour)
+                }
+            };
+            if first_hour > last_hour || schedule::wrap_around_range(minutes_expression).is_some() {
+                return None;
+            }
+            let time = |hour: u32, minute: u32, second: Option<u32>| {
+                let second = second.map_or(String::new(), |s| s.to_string());
+                format_time_secs(&hour.to_string(), &minute.to_string(), &second, options)
+            };
+            let (unit, step, first, last) = if seconds_expression.contains('/') {
+                if minutes_expression.contains(['/', ',']) {
+                    return None;
+                }
+                let seconds = schedule::parse_item(seconds_expression, 0, 59)?;
+                let minutes = schedule::parse_item(minutes_expression, 0, 59)?;
+                (
+                    "second",
+                    seconds_expression.split_once('/')?.1,
+                    time(first_hour, minutes[0], Some(seconds[0])),
+                    time(last_hour, *minutes.last()?, seconds.last().copied()),
+                )
+            } else if minutes_expression.contains('/') && seconds_expression.is_empty() {
+                let minutes = schedule::parse_item(minutes_expression, 0, 59)?;
+                (
+                    "minute",
+                    minutes_expression.split_once('/')?.1,
+                    time(first_hour, minutes[0], None),
+                    time(last_hour, *minutes.last()?, None),
+                )
+            } else {
+                return None;
+            };
+            let mut vars = HashMap::new();
+            vars.insert("0".to_string(), count(unit, step, options));
+            vars.insert("1".to_string(), first);
+            vars.insert("2".to_string(), last);
+            Some(strfmt::strfmt(&crate::inflection::agree_message("messages.every_x_between_x_and_y", unit), &vars).unwrap())
+        }
+
+        // Every combination of the hours, minutes and seconds, earliest first, when each is a
+        // list of single values and there are no more than `options.max_listed_times`.
+        fn get_listed_times(
+            seconds_expression: &str,
+            minutes_expression: &str,
+            hours_expression: &str,
+            options: &Options,
+            format: fn(&String, &String, &String, &Options) -> String,
+        ) -> Option<Vec<String>> {
+            let values = |expression: &str| -> Option<Vec<u32>> {
+                let mut values = expression
+                    .split(',')
+                    .map(|v| v.parse().ok())
+                    .collect::<Option<Vec<u32>>>()?;
+                values.sort_unstable();
+                Some(values)
+            };
+            let hours = values(hours_expression)?;
+            let minutes = values(minutes_expression)?;
+            let seconds: Vec<String> = if seconds_expression.is_empty() {
+                vec![String::new()]
+            } else {
+                values(seconds_expression)?.iter().map(|s| s.to_string()).collect()
+            };
+            let count = hours.len() * minutes.len() * seconds.len();
+            if count > options.max_listed_times {
+                return None;
+            }
+            let mut times = Vec::with_capacity(count);
+            for hour in &hours {
+                for minute in &minutes {
+                    for second in &seconds {
+                        times.push(format(
+                            &hour.to_string(),
+                            &minute.to_string(),
+                            second,
+                            options,
+                        ));
+                    }
+                }
+            }
+            Some(times)
+        }
+
         pub fn get_description_cron(expression: &str) -> Result<String, ParseException> {
             // println!("Expression: {}", expression);
             get_description(
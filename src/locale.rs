@@ -0,0 +1,604 @@
+//! Locales. The translations under `locales` are compiled in; further locales may be
+//! registered at runtime from YAML or JSON bundles, and single messages of any locale
+//! overridden, so that translations can be added and corrected without a new release.
+//!
+//! Messages are looked up in the overrides first, then in the bundle registered under the
+//! locale, and only then in the compiled translations. A bundle registered under the name of a
+//! compiled locale takes its place entirely.
+//!
+//! A locale asked for, such as a browser's `pt-BR` or `zh-Hant-TW`, is parsed as a BCP 47
+//! language tag and resolved to the available locale nearest to it, falling back to `en`.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_yaml::Value;
+
+// The translations under `locales`, parsed on first use as registered bundles are.
+mod compiled {
+    use std::collections::HashMap;
+
+    use once_cell::sync::Lazy;
+
+    use super::{flatten, Messages};
+
+    const FILES: &[(&str, &str)] = &[
+        ("ar", include_str!("../locales/ar.yml")),
+        ("cs", include_str!("../locales/cs.yml")),
+        ("de", include_str!("../locales/de.yml")),
+        ("en", include_str!("../locales/en.yml")),
+        ("es", include_str!("../locales/es.yml")),
+        ("fr", include_str!("../locales/fr.yml")),
+        ("he", include_str!("../locales/he.yml")),
+        ("it", include_str!("../locales/it.yml")),
+        ("ja", include_str!("../locales/ja.yml")),
+        ("ko", include_str!("../locales/ko.yml")),
+        ("nl", include_str!("../locales/nl.yml")),
+        ("pl", include_str!("../locales/pl.yml")),
+        ("pt", include_str!("../locales/pt.yml")),
+        ("ru", include_str!("../locales/ru.yml")),
+        ("uk", include_str!("../locales/uk.yml")),
+        ("zh-CN", include_str!("../locales/zh-CN.yml")),
+        ("zh-TW", include_str!("../locales/zh-TW.yml")),
+    ];
+
+    static TRANSLATIONS: Lazy<HashMap<&'static str, Messages>> = Lazy::new(|| {
+        FILES
+            .iter()
+            .map(|(locale, file)| {
+                let mut messages = Messages::new();
+                serde_yaml::from_str(file)
+                    .map_err(super::LocaleError::Yaml)
+                    .and_then(|value| flatten(&value, "", &mut messages))
+                    .unwrap_or_else(|e| panic!("locales/{}.yml: {:?}", locale, e));
+                (*locale, messages)
+            })
+            .collect()
+    });
+
+    /// Every compiled locale, sorted.
+    pub(super) fn available_locales() -> Vec<&'static str> {
+        FILES.iter().map(|(locale, _)| *locale).collect()
+    }
+
+    /// Keys of a compiled locale, empty when there is no such locale.
+    pub(super) fn keys(locale: &str) -> Vec<&'static str> {
+        TRANSLATIONS
+            .get(locale)
+            .map(|messages| messages.keys().map(String::as_str).collect())
+            .unwrap_or_default()
+    }
+
+    /// Message of a key in a compiled locale, `<locale>.<key>` when there is none.
+    pub(super) fn translate(locale: &str, key: &str) -> String {
+        TRANSLATIONS
+            .get(locale)
+            .and_then(|messages| messages.get(key))
+            .cloned()
+            .unwrap_or_else(|| format!("{}.{}", locale, key))
+    }
+}
+
+/// Why a bundle or an override was rejected.
+#[derive(Debug)]
+pub enum LocaleError {
+    Io(PathBuf, io::Error),
+    Yaml(serde_yaml::Error),
+    /// The bundle is not a mapping of keys to messages; the path of the offending value, empty
+    /// for the bundle itself.
+    Malformed(String),
+    /// Keys of `en` the bundle lacks.
+    MissingKeys(Vec<String>),
+    /// A locale neither compiled in nor registered.
+    UnknownLocale(String),
+    /// A key that neither the locale nor any compiled locale has.
+    UnknownKey(String),
+}
+
+/// The locale descriptions fall back to.
+pub const DEFAULT_LOCALE: &str = "en";
+
+// Locales standing for a script or region they are not named after: the compiled Chinese
+// locales are named after the regions whose script they are written in.
+const ALIASES: [(&str, &str); 5] = [
+    ("zh-Hans", "zh-CN"),
+    ("zh-Hant", "zh-TW"),
+    ("zh-HK", "zh-TW"),
+    ("zh-MO", "zh-TW"),
+    ("zh", "zh-CN"),
+];
+
+/// A BCP 47 language tag such as `pt-BR`, `es-419` or `zh-Hant-TW`, reduced to the subtags
+/// that choose a translation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageTag {
+    /// In lowercase, as `pt`.
+    pub language: String,
+    /// In title case, as `Hant`.
+    pub script: Option<String>,
+    /// In uppercase, as `BR`, or three digits, as `419`.
+    pub region: Option<String>,
+}
+
+impl LanguageTag {
+    /// Parses a tag in any case, with `-` or `_` between subtags. Variants and extensions are
+    /// ignored. `None` for anything else, such as `*` or the private use `x-klingon`.
+    pub fn parse(tag: &str) -> Option<LanguageTag> {
+        let mut subtags = tag.trim().split(['-', '_']).peekable();
+        let language = subtags.next()?;
+        if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            return None;
+        }
+        let script = subtags
+            .next_if(|s| s.len() == 4 && s.chars().all(|c| c.is_ascii_alphabetic()))
+            .map(|s| s[..1].to_ascii_uppercase() + &s[1..].to_ascii_lowercase());
+        let region = subtags
+            .next_if(|s| {
+                (s.len() == 2 && s.chars().all(|c| c.is_ascii_alphabetic()))
+                    || (s.len() == 3 && s.chars().all(|c| c.is_ascii_digit()))
+            })
+            .map(|s| s.to_ascii_uppercase());
+        if !subtags
+            .all(|s| (1..=8).contains(&s.len()) && s.chars().all(|c| c.is_ascii_alphanumeric()))
+        {
+            return None;
+        }
+        Some(LanguageTag {
+            language: language.to_ascii_lowercase(),
+            script,
+            region,
+        })
+    }
+
+    /// Locales to look for translations in, most specific first and ending with `en`:
+    /// `pt-BR` gives `pt-BR`, `pt` and `en`, and `zh-Hant-TW` gives `zh-Hant-TW`, `zh-Hant`,
+    /// `zh-TW`, `zh`, `zh-CN` and `en`.
+    pub fn fallback_chain(&self) -> Vec<String> {
+        let mut chain = self.chain_without_default();
+        if !chain.iter().any(|locale| locale == DEFAULT_LOCALE) {
+            chain.push(DEFAULT_LOCALE.to_string());
+        }
+        chain
+    }
+
+    fn chain_without_default(&self) -> Vec<String> {
+        let language = &self.language;
+        let candidates = [
+            self.script
+                .as_ref()
+                .zip(self.region.as_ref())
+                .map(|(s, r)| format!("{}-{}-{}", language, s, r)),
+            self.script.as_ref().map(|s| format!("{}-{}", language, s)),
+            self.region.as_ref().map(|r| format!("{}-{}", language, r)),
+            Some(language.clone()),
+        ];
+        let mut chain: Vec<String> = Vec::new();
+        for candidate in candidates.into_iter().flatten() {
+            let alias = ALIASES
+                .iter()
+                .find(|(tag, _)| *tag == candidate)
+                .map(|(_, locale)| locale.to_string());
+            for locale in [Some(candidate), alias].into_iter().flatten() {
+                if !chain.contains(&locale) {
+                    chain.push(locale);
+                }
+            }
+        }
+        chain
+    }
+}
+
+impl fmt::Display for LanguageTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.language)?;
+        for subtag in [&self.script, &self.region].into_iter().flatten() {
+            write!(f, "-{}", subtag)?;
+        }
+        Ok(())
+    }
+}
+
+type Messages = HashMap<String, String>;
+
+static BUNDLES: Lazy<RwLock<HashMap<String, Messages>>> = Lazy::new(Default::default);
+static OVERRIDES: Lazy<RwLock<HashMap<String, Messages>>> = Lazy::new(Default::default);
+
+/// Message of a key in a locale. Like rust-i18n, a missing message is answered with
+/// `<locale>.<key>`.
+pub(crate) fn translate(locale: &str, key: &str) -> String {
+    let overrides = OVERRIDES.read().unwrap();
+    if let Some(message) = overrides.get(locale).and_then(|messages| messages.get(key)) {
+        return message.clone();
+    }
+    match BUNDLES.read().unwrap().get(locale) {
+        Some(messages) => messages
+            .get(key)
+            .cloned()
+            .unwrap_or_else(|| format!("{}.{}", locale, key)),
+        None => compiled::translate(locale, key),
+    }
+}
+
+/// Whether a locale is compiled in or registered.
+pub fn is_available(locale: &str) -> bool {
+    BUNDLES.read().unwrap().contains_key(locale) || compiled::available_locales().contains(&locale)
+}
+
+/// Every locale compiled in or registered, sorted.
+pub fn available_locales() -> Vec<String> {
+    let mut locales: Vec<String> = compiled::available_locales()
+        .iter()
+        .map(|l| l.to_string())
+        .collect();
+    locales.extend(BUNDLES.read().unwrap().keys().cloned());
+    locales.sort();
+    locales.dedup();
+    locales
+}
+
+// The available locale a name stands for, whatever its case and whether `-` or `_` separates
+// its subtags.
+fn find_available(locale: &str) -> Option<String> {
+    let same = |available: &String| {
+        available
+            .replace('_', "-")
+            .eq_ignore_ascii_case(&locale.replace('_', "-"))
+    };
+    available_locales().into_iter().find(same)
+}
+
+/// The available locale descriptions asked for in `locale` are written in: `locale` itself if
+/// compiled in or registered, otherwise the first available locale of its fallback chain, so
+/// `pt-BR` resolves to `pt`. [`DEFAULT_LOCALE`] when nothing nearer is available or `locale` is
+/// not a language tag.
+pub fn resolve(locale: &str) -> String {
+    find_available(locale)
+        .or_else(|| {
+            LanguageTag::parse(locale)?
+                .fallback_chain()
+                .iter()
+                .find_map(|locale| find_available(locale))
+        })
+        .unwrap_or_else(|| DEFAULT_LOCALE.to_string())
+}
+
+/// The available locale best matching an `Accept-Language` header value such as
+/// `fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5`. Languages are tried by decreasing quality, in the
+/// order given for equal qualities, each resolved as by [`resolve`] but without falling back
+/// to [`DEFAULT_LOCALE`]; that is the answer only when none of them is available.
+pub fn negotiate(accept_language: &str) -> String {
+    let mut ranges: Vec<(&str, f32)> = accept_language
+        .split(',')
+        .filter_map(|range| {
+            let mut parts = range.split(';');
+            let tag = parts.next()?.trim();
+            let quality = parts
+                .filter_map(|parameter| parameter.trim().strip_prefix("q="))
+                .next()
+                .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok())?;
+            Some((tag, quality)).filter(|_| quality > 0.0)
+        })
+        .collect();
+    ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranges
+        .iter()
+        .find_map(|(tag, _)| {
+            find_available(tag).or_else(|| {
+                LanguageTag::parse(tag)?
+                    .chain_without_default()
+                    .iter()
+                    .find_map(|locale| find_available(locale))
+            })
+        })
+        .unwrap_or_else(|| DEFAULT_LOCALE.to_string())
+}
+
+/// Keys of a locale, compiled in or registered, and those overridden.
+pub(crate) fn keys(locale: &str) -> Vec<String> {
+    let mut keys: Vec<String> = match BUNDLES.read().unwrap().get(locale) {
+        Some(messages) => messages.keys().cloned().collect(),
+        None => compiled::keys(locale)
+            .into_iter()
+            .map(str::to_string)
+            .collect(),
+    };
+    if let Some(overrides) = OVERRIDES.read().unwrap().get(locale) {
+        keys.extend(overrides.keys().cloned());
+    }
+    keys.sort();
+    keys.dedup();
+    keys
+}
+
+// Keys every locale must give: those of `en`, but for the plural and ordinal forms other than
+// `other`, which languages without such forms leave out.
+fn required_keys() -> Vec<&'static str> {
+    compiled::keys("en")
+        .into_iter()
+        .filter(|key| {
+            (!(key.starts_with("plurals.") || key.starts_with("ordinals."))
+                || key.ends_with(".other"))
+                && !OPTIONAL_SECTIONS.iter().any(|s| key.starts_with(s))
+        })
+        .collect()
+}
+
+/// Registers a locale from a bundle in YAML, or JSON, laid out like the files under `locales`.
+/// The bundle must give every message `en` does; it may also give the optional keys such as
+/// `separator` or `cases`.
+pub fn register_locale(locale: &str, bundle: &str) -> Result<(), LocaleError> {
+    let value: Value = serde_yaml::from_str(bundle).map_err(LocaleError::Yaml)?;
+    let mut messages = Messages::new();
+    flatten(&value, "", &mut messages)?;
+    let mut missing: Vec<String> = required_keys()
+        .into_iter()
+        .filter(|key| !messages.contains_key(*key))
+        .map(str::to_string)
+        .collect();
+    if !missing.is_empty() {
+        missing.sort();
+        return Err(LocaleError::MissingKeys(missing));
+    }
+    BUNDLES
+        .write()
+        .unwrap()
+        .insert(locale.to_string(), messages);
+    Ok(())
+}
+
+/// Registers a locale from a YAML or JSON file, as [`register_locale`].
+pub fn register_locale_file(locale: &str, path: &Path) -> Result<(), LocaleError> {
+    let bundle = fs::read_to_string(path).map_err(|e| LocaleError::Io(path.to_path_buf(), e))?;
+    register_locale(locale, &bundle)
+}
+
+/// Overrides one message of a locale, compiled in or registered, such as
+/// `messages.every_x`. So that a misspelt key is caught, the key must be one the locale or
+/// any compiled locale has.
+pub fn override_message(locale: &str, key: &str, message: &str) -> Result<(), LocaleError> {
+    if !is_available(locale) {
+        return Err(LocaleError::UnknownLocale(locale.to_string()));
+    }
+    let compiled_key = compiled::available_locales()
+        .iter()
+        .any(|locale| compiled::keys(locale).contains(&key));
+    if !compiled_key && !keys(locale).iter().any(|k| k == key) {
+        return Err(LocaleError::UnknownKey(key.to_string()));
+    }
+    OVERRIDES
+        .write()
+        .unwrap()
+        .entry(locale.to_string())
+        .or_default()
+        .insert(key.to_string(), message.to_string());
+    Ok(())
+}
+
+/// How a locale differs from `en`, as found by [`check_locale`].
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct LocaleReport {
+    pub locale: String,
+    /// Keys `en` gives and the locale does not, whose messages would read as the raw key.
+    pub missing_keys: Vec<String>,
+    /// Keys neither `en` has nor any locale may add, such as misspelt ones.
+    pub extra_keys: Vec<String>,
+    pub placeholder_mismatches: Vec<PlaceholderMismatch>,
+}
+
+impl LocaleReport {
+    /// Whether the locale lacks nothing and has nothing amiss.
+    pub fn is_complete(&self) -> bool {
+        self.missing_keys.is_empty()
+            && self.extra_keys.is_empty()
+            && self.placeholder_mismatches.is_empty()
+    }
+}
+
+/// A message whose placeholders, such as `{0}`, `{nth}` or `%{0}`, are not those of the message
+/// of `en` it translates.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PlaceholderMismatch {
+    pub key: String,
+    /// Placeholders of the message of `en` this one lacks, which would go undescribed.
+    pub missing: Vec<String>,
+    /// Placeholders of this message `en`'s lacks, which would be left in descriptions as is.
+    pub unexpected: Vec<String>,
+}
+
+// Top-level keys a locale may give to tune how descriptions are put together.
+const OPTIONAL_KEYS: &[&str] = &[
+    "direction",
+    "separator",
+    "list_separator",
+    "last_list_separator",
+    "period_position",
+    "segment_order",
+    "space_between_words",
+];
+
+// Messages a locale may give, with the message used in their place when it does not.
+const OPTIONAL_MESSAGES: &[(&str, &str)] = &[
+    (
+        "messages.list_range_description_format",
+        "messages.between_description_format",
+    ),
+    (
+        "messages.month_list_range_description_format",
+        "messages.between_description_format",
+    ),
+];
+
+// Sections a locale may give for grammatical gender and case, and words compact descriptions
+// may leave out.
+const OPTIONAL_SECTIONS: &[&str] = &["cases.", "genders.", "inflections.", "optional_words."];
+
+// Forms of a message a locale may give for units of each gender, as `messages.every_x_feminine`.
+const GENDERS: &[&str] = &["masculine", "feminine", "neuter"];
+
+// Plural and ordinal categories, as CLDR names them.
+const CATEGORIES: &[&str] = &["zero", "one", "two", "few", "many", "other"];
+
+// Placeholders a locale may add to a message, as the word for "last" in languages placing it
+// before the day rather than where `en` has it.
+const ADDED_PLACEHOLDERS: &[(&str, &str)] = &[
+    ("messages.the_last_x", "{last}"),
+    ("messages.on_the_last_of_the_month", "{last}"),
+];
+
+// Messages whose `{1}` is the unit `{0}` counts. A locale whose forms of that unit place the
+// number themselves, as `{0} ثوانٍ`, leaves `{0}` out.
+const COUNTED_UNITS: &[(&str, &str)] = &[
+    ("messages.interval_description_format", "day"),
+    ("messages.days_before_the_last_day_of_the_month", "day"),
+    ("messages.at_x_minutes_past_the_hour", "minute"),
+    ("messages.at_x_seconds_past_the_minute", "second"),
+];
+
+/// Compares a locale, compiled in or registered and with its overrides, against `en`: the keys
+/// it lacks, the keys it should not have and the messages whose placeholders differ. `None`
+/// when the locale is not available.
+pub fn check_locale(locale: &str) -> Option<LocaleReport> {
+    if !is_available(locale) {
+        return None;
+    }
+    let english = compiled::keys("en");
+    let keys = keys(locale);
+    let mut report = LocaleReport {
+        locale: locale.to_string(),
+        missing_keys: required_keys()
+            .into_iter()
+            .filter(|key| !keys.iter().any(|k| k == key))
+            .map(str::to_string)
+            .collect(),
+        ..Default::default()
+    };
+    report.missing_keys.sort();
+    for key in &keys {
+        let Some(original) = original_key(key, &english) else {
+            report.extra_keys.push(key.clone());
+            continue;
+        };
+        if !original.starts_with("messages.")
+            && !original.starts_with("compact.")
+            && !original.starts_with("ordinals.")
+        {
+            continue;
+        }
+        let expected = placeholders(&compiled::translate("en", &original));
+        let found = placeholders(&translate(locale, key));
+        let missing: Vec<String> = expected
+            .iter()
+            .filter(|p| !found.contains(p) && !may_leave_out(locale, &original, p))
+            .cloned()
+            .collect();
+        let unexpected: Vec<String> = found
+            .iter()
+            .filter(|p| {
+                !expected.contains(p) && !ADDED_PLACEHOLDERS.contains(&(original.as_str(), p))
+            })
+            .cloned()
+            .collect();
+        if !missing.is_empty() || !unexpected.is_empty() {
+            report.placeholder_mismatches.push(PlaceholderMismatch {
+                key: key.clone(),
+                missing,
+                unexpected,
+            });
+        }
+    }
+    Some(report)
+}
+
+/// [`check_locale`] for every available locale, sorted.
+pub fn check_locales() -> Vec<LocaleReport> {
+    available_locales()
+        .iter()
+        .filter_map(|locale| check_locale(locale))
+        .collect()
+}
+
+// The key of `en` a key of another locale translates, `None` for a key no locale may have.
+// Optional keys stand for themselves, optional messages for the message used in their place,
+// and plural and ordinal forms for `other`.
+fn original_key(key: &str, english: &[&str]) -> Option<String> {
+    if english.contains(&key) {
+        return Some(key.to_string());
+    }
+    if let Some((_, fallback)) = OPTIONAL_MESSAGES
+        .iter()
+        .find(|(optional, _)| *optional == key)
+    {
+        return Some(fallback.to_string());
+    }
+    if OPTIONAL_KEYS.contains(&key) || OPTIONAL_SECTIONS.iter().any(|s| key.starts_with(s)) {
+        return Some(key.to_string());
+    }
+    if let Some((base, gender)) = key.rsplit_once('_') {
+        if key.starts_with("messages.") && GENDERS.contains(&gender) && english.contains(&base) {
+            return Some(base.to_string());
+        }
+    }
+    let (section, category) = key.rsplit_once('.')?;
+    let other = format!("{}.other", section);
+    (CATEGORIES.contains(&category) && english.contains(&other.as_str())).then_some(other)
+}
+
+// Whether a locale may leave a placeholder of `en`'s message out.
+fn may_leave_out(locale: &str, key: &str, placeholder: &str) -> bool {
+    placeholder == "{0}"
+        && COUNTED_UNITS.iter().any(|(message, unit)| {
+            *message == key && translate(locale, &format!("plurals.{}.other", unit)).contains("{0}")
+        })
+}
+
+// The placeholders of a message, sorted, each once.
+fn placeholders(message: &str) -> Vec<String> {
+    static PLACEHOLDER: Lazy<Regex> = Lazy::new(|| Regex::new(r"%?\{[A-Za-z0-9_]*\}").unwrap());
+    let mut placeholders: Vec<String> = PLACEHOLDER
+        .find_iter(message)
+        .map(|placeholder| placeholder.as_str().to_string())
+        .collect();
+    placeholders.sort();
+    placeholders.dedup();
+    placeholders
+}
+
+// Messages of a bundle by their dotted keys, as `messages.every_x`.
+fn flatten(value: &Value, path: &str, messages: &mut Messages) -> Result<(), LocaleError> {
+    let message = match value {
+        Value::Mapping(mapping) => {
+            for (key, value) in mapping {
+                let key = match key {
+                    Value::String(key) => key.clone(),
+                    Value::Bool(key) => key.to_string(),
+                    Value::Number(key) => key.to_string(),
+                    _ => return Err(LocaleError::Malformed(path.to_string())),
+                };
+                let path = if path.is_empty() {
+                    key
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                flatten(value, &path, messages)?;
+            }
+            return Ok(());
+        }
+        Value::String(message) => message.clone(),
+        Value::Bool(message) => message.to_string(),
+        Value::Number(message) => message.to_string(),
+        Value::Null => String::new(),
+        Value::Sequence(_) => return Err(LocaleError::Malformed(path.to_string())),
+    };
+    if path.is_empty() {
+        return Err(LocaleError::Malformed(String::new()));
+    }
+    messages.insert(path.to_string(), message);
+    Ok(())
+}
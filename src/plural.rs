@@ -0,0 +1,119 @@
+//! CLDR plural rules, which pick the form of a counted word such as "1 minute" or "5 minutes"
+//! and of an ordinal such as "1st" or "2nd".
+//!
+//! Locale files give a form for each category their language uses, under `plurals.<unit>`
+//! for counted units and `ordinals` for ordinals. A missing category falls back to `other`.
+//!
+//! Where the `other` form of a unit places the number itself with `{0}`, as in "{0} دقيقة",
+//! every form of the unit does, so that a form may leave the number out: Arabic says "every
+//! two minutes" with the dual "دقيقتين" alone.
+
+use crate::inflection::translate_optional;
+
+// Language subtag of a locale, the `pt` of `pt-BR`.
+fn language(locale: &str) -> &str {
+    locale.split(['-', '_']).next().unwrap_or(locale)
+}
+
+/// CLDR cardinal plural category of a whole number in a locale.
+pub(crate) fn cardinal_category(locale: &str, number: u32) -> &'static str {
+    let (last_digit, last_two_digits) = (number % 10, number % 100);
+    match language(locale) {
+        "ru" | "uk" => match (last_digit, last_two_digits) {
+            (1, n) if n != 11 => "one",
+            (2..=4, n) if !(12..=14).contains(&n) => "few",
+            _ => "many",
+        },
+        "pl" => match (number, last_digit, last_two_digits) {
+            (1, _, _) => "one",
+            (_, 2..=4, n) if !(12..=14).contains(&n) => "few",
+            _ => "many",
+        },
+        "ar" => match (number, last_two_digits) {
+            (0, _) => "zero",
+            (1, _) => "one",
+            (2, _) => "two",
+            (_, 3..=10) => "few",
+            (_, 11..=99) => "many",
+            _ => "other",
+        },
+        "he" => match number {
+            1 => "one",
+            2 => "two",
+            _ => "other",
+        },
+        "cs" => match number {
+            1 => "one",
+            2..=4 => "few",
+            _ => "other",
+        },
+        // French and Portuguese count 0 with the singular.
+        "fr" | "pt" => match number {
+            0 | 1 => "one",
+            _ => "other",
+        },
+        _ => match number {
+            1 => "one",
+            _ => "other",
+        },
+    }
+}
+
+/// CLDR ordinal plural category of a whole number in a locale.
+pub(crate) fn ordinal_category(locale: &str, number: u32) -> &'static str {
+    match language(locale) {
+        "en" => match (number % 10, number % 100) {
+            (1, n) if n != 11 => "one",
+            (2, n) if n != 12 => "two",
+            (3, n) if n != 13 => "few",
+            _ => "other",
+        },
+        "fr" => match number {
+            1 => "one",
+            _ => "other",
+        },
+        _ => "other",
+    }
+}
+
+/// Word for a `second`, `minute`, `hour`, `day`, `month` or `year` counted by an expression in
+/// the current locale. A list such as `5,10` agrees with its last number, though never in the
+/// singular or the dual, and anything else that is not a whole number counts as `other`.
+pub(crate) fn unit(unit: &str, expression: &str) -> String {
+    let locale = rust_i18n::locale();
+    let category = match expression.rsplit_once(',') {
+        Some((_, last)) => match last.parse::<u32>() {
+            Ok(number) => match cardinal_category(&locale, number) {
+                "one" | "two" => "other",
+                category => category,
+            },
+            Err(_) => "other",
+        },
+        None => match expression.parse::<u32>() {
+            Ok(number) => cardinal_category(&locale, number),
+            Err(_) => "other",
+        },
+    };
+    translate_category(&format!("plurals.{}", unit), category)
+}
+
+/// A number and the word for the unit it counts, such as "5 minutes", `space` apart.
+pub(crate) fn count(unit_key: &str, number: &str, space: &str) -> String {
+    let word = unit(unit_key, number);
+    if t!(&format!("plurals.{}.other", unit_key)).contains("{0}") {
+        word.replace("{0}", number)
+    } else {
+        format!("{}{}{}", number, space, word)
+    }
+}
+
+/// Ordinal of a number in the current locale, such as "1st", "1.º" or "1º".
+pub(crate) fn ordinal(number: u32) -> String {
+    let category = ordinal_category(&rust_i18n::locale(), number);
+    translate_category("ordinals", category).replace("{0}", &number.to_string())
+}
+
+fn translate_category(key: &str, category: &str) -> String {
+    translate_optional(&format!("{}.{}", key, category))
+        .unwrap_or_else(|| t!(&format!("{}.other", key)))
+}
@@ -0,0 +1,661 @@
+//! Typed view of a cron expression: the values each field matches, the Quartz
+//! day-of-month and day-of-week specials, and a search for the next occurrence.
+
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::cronparser::cron_expression_descriptor::{expression_parser, ParseException};
+use crate::cronparser::{NameLocale, Options};
+use crate::date_time_utils::{
+    civil_from_days, day_of_week, days_from_civil, days_in_month, DAY_OF_WEEK_KEYS, MONTHS_ARR,
+};
+
+// Bounds of seconds, minutes, hours, day of month, month, day of week and year.
+const FIELD_BOUNDS: [(u32, u32); 7] = [
+    (0, 59),
+    (0, 59),
+    (0, 23),
+    (1, 31),
+    (1, 12),
+    (0, 7),
+    (1970, 2099),
+];
+pub(crate) const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+pub(crate) const DAY_OF_WEEK_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+// Full names accepted in place of the three-letter ones above.
+const FULL_NAMES: [&str; 18] = [
+    "JANUARY",
+    "FEBRUARY",
+    "MARCH",
+    "APRIL",
+    "JUNE",
+    "JULY",
+    "AUGUST",
+    "SEPTEMBER",
+    "OCTOBER",
+    "NOVEMBER",
+    "DECEMBER",
+    "SUNDAY",
+    "MONDAY",
+    "TUESDAY",
+    "WEDNESDAY",
+    "THURSDAY",
+    "FRIDAY",
+    "SATURDAY",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    /// Sorted, de-duplicated values matched by the field.
+    pub values: Vec<u32>,
+    /// True when the field was `*` (or `?`), i.e. it does not restrict anything.
+    pub wildcard: bool,
+}
+
+impl Field {
+    pub fn contains(&self, value: u32) -> bool {
+        self.wildcard || self.values.binary_search(&value).is_ok()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DayOfMonthItem {
+    Day(u32),
+    /// `L`
+    LastDay,
+    /// `L-3`, the given number of days before the last day.
+    LastDayOffset(u32),
+    /// `LW`
+    LastWeekday,
+    /// `15W`
+    NearestWeekday(u32),
+}
+
+/// Days of the week are numbered from 0 (Sunday) to 6 (Saturday) whatever the
+/// `zero_based_day_of_week` setting of the parsed expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DayOfWeekItem {
+    Day(u32),
+    /// `5L`, the last given weekday of the month.
+    Last(u32),
+    /// `1#3`, the given weekday's nth occurrence in the month.
+    Nth(u32, u32),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schedule {
+    pub seconds: Field,
+    pub minutes: Field,
+    pub hours: Field,
+    /// Empty when the day of month is unrestricted.
+    pub days_of_month: Vec<DayOfMonthItem>,
+    pub months: Field,
+    /// Empty when the day of week is unrestricted.
+    pub days_of_week: Vec<DayOfWeekItem>,
+    pub years: Field,
+    /// Whether a day matching either restricted day field fires, rather than only a day
+    /// matching both. See [`Options::match_any_day`] and [`matches_any_day`].
+    pub match_any_day: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DateTime {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+}
+
+impl DateTime {
+    pub fn new(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> DateTime {
+        DateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        }
+    }
+
+    pub fn from_unix_timestamp(seconds: i64) -> DateTime {
+        let (year, month, day) = civil_from_days(seconds.div_euclid(86400));
+        let time = seconds.rem_euclid(86400) as u32;
+        DateTime::new(year, month, day, time / 3600, time % 3600 / 60, time % 60)
+    }
+
+    pub fn unix_timestamp(&self) -> i64 {
+        days_from_civil(self.year, self.month, self.day) * 86400
+            + (self.hour * 3600 + self.minute * 60 + self.second) as i64
+    }
+
+    pub fn now_utc() -> DateTime {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+        DateTime::from_unix_timestamp(seconds)
+    }
+
+    /// 0 = Sunday.
+    pub fn day_of_week(&self) -> u32 {
+        day_of_week(self.year, self.month, self.day)
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+pub fn parse(expression: &str, options: &Options) -> Result<Schedule, ParseException> {
+    let parts = expression_parser::parse(expression, options)?;
+    Schedule::from_parts(expression, &parts, options)
+}
+
+impl DayOfMonthItem {
+    pub fn matches(&self, year: i32, month: u32, day: u32) -> bool {
+        match *self {
+            DayOfMonthItem::Day(d) => d == day,
+            DayOfMonthItem::LastDay => day == days_in_month(year, month),
+            DayOfMonthItem::LastDayOffset(n) => day + n == days_in_month(year, month),
+            DayOfMonthItem::LastWeekday => day == last_weekday_of_month(year, month),
+            DayOfMonthItem::NearestWeekday(d) => {
+                d <= days_in_month(year, month) && day == nearest_weekday(year, month, d)
+            }
+        }
+    }
+}
+
+impl DayOfWeekItem {
+    pub fn matches(&self, year: i32, month: u32, day: u32) -> bool {
+        let weekday = day_of_week(year, month, day);
+        match *self {
+            DayOfWeekItem::Day(d) => d == weekday,
+            DayOfWeekItem::Last(d) => d == weekday && day + 7 > days_in_month(year, month),
+            DayOfWeekItem::Nth(d, nth) => d == weekday && (day - 1) / 7 + 1 == nth,
+        }
+    }
+}
+
+impl Schedule {
+    /// Builds a schedule from the seven parts produced by the expression parser.
+    pub(crate) fn from_parts(
+        expression: &str,
+        parts: &[String],
+        options: &Options,
+    ) -> Result<Schedule, ParseException> {
+        let seconds = if parts[0].is_empty() { "0" } else { &parts[0] };
+        let first_day_of_week = if options.zero_based_day_of_week { 0 } else { 1 };
+        let months = replace_localized_names(
+            &parts[4],
+            &MONTH_NAMES,
+            &localized_names(&MONTHS_ARR, options),
+            1,
+        );
+        let days_of_week = replace_localized_names(
+            &parts[5],
+            &DAY_OF_WEEK_NAMES,
+            &localized_names(&DAY_OF_WEEK_KEYS, options),
+            first_day_of_week,
+        );
+        Ok(Schedule {
+            seconds: parse_field(expression, 0, seconds)?,
+            minutes: parse_field(expression, 1, &parts[1])?,
+            hours: parse_field(expression, 2, &parts[2])?,
+            days_of_month: parse_days_of_month(expression, &parts[3])?,
+            months: parse_field(expression, 4, &months)?,
+            days_of_week: parse_days_of_week(expression, &days_of_week, options)?,
+            years: parse_field(expression, 6, &parts[6])?,
+            match_any_day: matches_any_day(&parts[3], &parts[5], options),
+        })
+    }
+
+    pub fn matches_date(&self, year: i32, month: u32, day: u32) -> bool {
+        if !u32::try_from(year).is_ok_and(|y| self.years.contains(y))
+            || !self.months.contains(month)
+        {
+            return false;
+        }
+        let dom = || {
+            self.days_of_month
+                .iter()
+                .any(|i| i.matches(year, month, day))
+        };
+        let dow = || {
+            self.days_of_week
+                .iter()
+                .any(|i| i.matches(year, month, day))
+        };
+        match (self.days_of_month.is_empty(), self.days_of_week.is_empty()) {
+            (true, true) => true,
+            (false, true) => dom(),
+            (true, false) => dow(),
+            (false, false) if self.match_any_day => dom() || dow(),
+            (false, false) => dom() && dow(),
+        }
+    }
+
+    /// The first time at or after `from` that the schedule fires, if any before the end of
+    /// the year range.
+    pub fn next_occurrence(&self, from: &DateTime) -> Option<DateTime> {
+        let last_year = *self.years.values.last()? as i32;
+        let mut days = days_from_civil(from.year, from.month, from.day);
+        let mut earliest = (from.hour, from.minute, from.second);
+        while days <= days_from_civil(last_year, 12, 31) {
+            let (year, month, day) = civil_from_days(days);
+            if self.matches_date(year, month, day) {
+                if let Some((hour, minute, second)) = self.first_time_from(earliest) {
+                    return Some(DateTime::new(year, month, day, hour, minute, second));
+                }
+            }
+            earliest = (0, 0, 0);
+            days += 1;
+        }
+        None
+    }
+
+    fn first_time_from(&self, (hour, minute, second): (u32, u32, u32)) -> Option<(u32, u32, u32)> {
+        for &h in self.hours.values.iter().filter(|h| **h >= hour) {
+            for &m in self
+                .minutes
+                .values
+                .iter()
+                .filter(|m| h > hour || **m >= minute)
+            {
+                let later = h > hour || m > minute;
+                if let Some(&s) = self.seconds.values.iter().find(|s| later || **s >= second) {
+                    return Some((h, m, s));
+                }
+            }
+        }
+        None
+    }
+}
+
+fn last_weekday_of_month(year: i32, month: u32) -> u32 {
+    let last = days_in_month(year, month);
+    match day_of_week(year, month, last) {
+        0 => last - 2,
+        6 => last - 1,
+        _ => last,
+    }
+}
+
+// Quartz semantics: the nearest weekday never crosses into another month.
+fn nearest_weekday(year: i32, month: u32, day: u32) -> u32 {
+    let last = days_in_month(year, month);
+    match day_of_week(year, month, day) {
+        6 if day == 1 => 3,
+        6 => day - 1,
+        0 if day == last => day - 2,
+        0 => day + 1,
+        _ => day,
+    }
+}
+
+/// Replaces month or day names (three-letter or full, any case) with their numbers, the
+/// first name being `first_value`. A name directly followed by `L` (`FRIL`) keeps the `L`.
+/// Anything else is upper-cased and left as it is.
+pub(crate) fn replace_names(part: &str, names: &[&str], first_value: usize) -> String {
+    replace_localized_names(part, names, &[], first_value)
+}
+
+/// [`replace_names`], also replacing the names of another language: `localized[i]` are those
+/// of the value of `names[i]`, folded as by `fold_name`. English names come first, so that a
+/// month `MAR` is March whatever the language.
+pub(crate) fn replace_localized_names(
+    part: &str,
+    names: &[&str],
+    localized: &[Vec<String>],
+    first_value: usize,
+) -> String {
+    let number = |word: &str| {
+        let index = names
+            .iter()
+            .position(|name| {
+                word == *name || (word.starts_with(name) && FULL_NAMES.contains(&word))
+            })
+            .or_else(|| {
+                let word = fold_name(word);
+                localized.iter().position(|names| names.contains(&word))
+            })?;
+        Some((index + first_value).to_string())
+    };
+    let mut replaced = String::new();
+    let mut word = String::new();
+    for c in part.chars().chain(std::iter::once(' ')) {
+        if c.is_alphabetic() {
+            word.extend(c.to_uppercase());
+            continue;
+        }
+        if !word.is_empty() {
+            let last = word.strip_suffix('L').and_then(number).map(|n| n + "L");
+            replaced.push_str(&number(&word).or(last).unwrap_or_else(|| word.clone()));
+            word.clear();
+        }
+        replaced.push(c);
+    }
+    replaced.pop();
+    replaced
+}
+
+/// The names an expression may use for each of `keys`, such as `DAY_OF_WEEK_KEYS`, in the
+/// language of [`Options::name_locale`]: the full and abbreviated names of descriptions,
+/// folded as by `fold_name`. Names that are not a single word, such as "segunda-feira" or
+/// "1月", cannot be told apart from the rest of an expression and are left out, as are names a
+/// locale leaves empty.
+pub(crate) fn localized_names(keys: &[&str], options: &Options) -> Vec<Vec<String>> {
+    let locale = match options.name_locale {
+        NameLocale::English => return Vec::new(),
+        NameLocale::Current => rust_i18n::locale(),
+        NameLocale::Locale(locale) => crate::locale::resolve(locale),
+    };
+    let missing = format!("{}.", locale);
+    keys.iter()
+        .map(|key| {
+            [key.to_string(), format!("abbreviations.{}", key)]
+                .iter()
+                .map(|key| crate::locale::translate(&locale, key))
+                .filter(|name| {
+                    !name.is_empty()
+                        && !name.starts_with(&missing)
+                        && name.chars().all(char::is_alphabetic)
+                })
+                .map(|name| fold_name(&name))
+                .collect()
+        })
+        .collect()
+}
+
+// A name upper-cased and without the accents of Latin letters, so that `mié`, `MIE` and `Mié`
+// are the same.
+fn fold_name(name: &str) -> String {
+    name.chars()
+        .flat_map(char::to_uppercase)
+        .map(|c| match c {
+            'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' => 'A',
+            'Ç' => 'C',
+            'È' | 'É' | 'Ê' | 'Ë' => 'E',
+            'Ì' | 'Í' | 'Î' | 'Ï' => 'I',
+            'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' => 'O',
+            'Ù' | 'Ú' | 'Û' | 'Ü' => 'U',
+            c => c,
+        })
+        .collect()
+}
+
+fn parse_error(expression: &str, field: usize) -> ParseException {
+    ParseException {
+        s: expression.to_string(),
+        error_offset: field as u8,
+    }
+}
+
+fn parse_field(expression: &str, field: usize, part: &str) -> Result<Field, ParseException> {
+    let (min, max) = FIELD_BOUNDS[field];
+    if part.is_empty() || part == "*" {
+        return Ok(Field {
+            values: (min..=max).collect(),
+            wildcard: true,
+        });
+    }
+    let mut values = Vec::new();
+    for item in part.split(',') {
+        // Years do not wrap around.
+        if field == 6 && wrap_around_range(item).is_some() {
+            return Err(parse_error(expression, field));
+        }
+        values.extend(parse_item(item, min, max).ok_or_else(|| parse_error(expression, field))?);
+    }
+    values.sort_unstable();
+    values.dedup();
+    Ok(Field {
+        values,
+        wildcard: false,
+    })
+}
+
+// A single value, `a-b` range or `*`, `a` or `a-b` followed by a `/step`.
+pub(crate) fn parse_item(item: &str, min: u32, max: u32) -> Option<Vec<u32>> {
+    let (range, step) = match item.split_once('/') {
+        Some((range, step)) => (range, step.parse::<usize>().ok().filter(|s| *s > 0)?),
+        None => (item, 0),
+    };
+    let (start, end) = if range == "*" {
+        (min, max)
+    } else if let Some((start, end)) = range.split_once('-') {
+        (start.parse().ok()?, end.parse().ok()?)
+    } else {
+        let value = range.parse().ok()?;
+        (value, if step > 0 { max } else { value })
+    };
+    if start < min || start > max || end < min || end > max {
+        return None;
+    }
+    // A range ending before its start wraps around, `22-2` is 22, 23, 0, 1 and 2.
+    let cycle = max - min + 1;
+    let length = (end + cycle - start) % cycle + 1;
+    Some(
+        (0..length)
+            .step_by(step.max(1))
+            .map(|i| min + (start - min + i) % cycle)
+            .collect(),
+    )
+}
+
+/// The start and end of an `a-b` or `a-b/step` item when `b` is before `a`.
+pub(crate) fn wrap_around_range(item: &str) -> Option<(u32, u32)> {
+    let range = item.split_once('/').map_or(item, |(range, _)| range);
+    let (start, end) = range.split_once('-')?;
+    let (start, end) = (start.parse::<u32>().ok()?, end.parse::<u32>().ok()?);
+    (start > end).then_some((start, end))
+}
+
+/// A field with each range ending before its start spelt out as the ranges up to `max` and
+/// from `min`, `50-10` as `50-59,0-10`, or as the values it matches when stepped.
+pub(crate) fn unwrap_ranges(part: &str, min: u32, max: u32) -> String {
+    let range = |start: u32, end: u32| {
+        if start == end {
+            start.to_string()
+        } else {
+            format!("{}-{}", start, end)
+        }
+    };
+    part.split(',')
+        .map(|item| match wrap_around_range(item) {
+            Some(_) if item.contains('/') => {
+                parse_item(item, min, max).map_or(item.to_string(), |values| {
+                    values
+                        .iter()
+                        .map(u32::to_string)
+                        .collect::<Vec<_>>()
+                        .join(",")
+                })
+            }
+            Some((start, end)) => format!("{},{}", range(start, max), range(min, end)),
+            None => item.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn parse_days_of_month(
+    expression: &str,
+    part: &str,
+) -> Result<Vec<DayOfMonthItem>, ParseException> {
+    let mut items = Vec::new();
+    if part == "*" {
+        return Ok(items);
+    }
+    for item in part.split(',') {
+        if item.contains(['L', 'W']) {
+            items.push(parse_day_of_month_item(item).ok_or_else(|| parse_error(expression, 3))?);
+        } else {
+            let days = parse_item(item, 1, 31).ok_or_else(|| parse_error(expression, 3))?;
+            items.extend(days.into_iter().map(DayOfMonthItem::Day));
+        }
+    }
+    Ok(items)
+}
+
+/// Parses a single day of month: a number or one of the Quartz `L`, `L-n`, `LW` and `nW`
+/// forms.
+pub(crate) fn parse_day_of_month_item(item: &str) -> Option<DayOfMonthItem> {
+    let day = |s: &str| s.parse::<u32>().ok().filter(|d| (1..=31).contains(d));
+    match item {
+        "L" => Some(DayOfMonthItem::LastDay),
+        "LW" | "WL" => Some(DayOfMonthItem::LastWeekday),
+        _ => {
+            if let Some(offset) = item.strip_prefix("L-") {
+                // The last day is at least the 28th.
+                let offset = offset
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|n| (1..=30).contains(n))?;
+                Some(DayOfMonthItem::LastDayOffset(offset))
+            } else if let Some(d) = item.strip_suffix('W').or_else(|| item.strip_prefix('W')) {
+                day(d).map(DayOfMonthItem::NearestWeekday)
+            } else {
+                day(item).map(DayOfMonthItem::Day)
+            }
+        }
+    }
+}
+
+fn parse_days_of_week(
+    expression: &str,
+    part: &str,
+    options: &Options,
+) -> Result<Vec<DayOfWeekItem>, ParseException> {
+    let mut items = Vec::new();
+    if part == "*" {
+        return Ok(items);
+    }
+    for item in part.split(',') {
+        if item.contains(['L', '#']) || !item.contains(['-', '/', '*']) {
+            items.push(
+                parse_day_of_week_item(item, options.zero_based_day_of_week)
+                    .ok_or_else(|| parse_error(expression, 5))?,
+            );
+        } else if let Some((start, end)) = wrap_around_range(item) {
+            // Weeks wrap around after 7 days however Sunday is numbered.
+            if start > 7 || end < first_day_of_week(options.zero_based_day_of_week) {
+                return Err(parse_error(expression, 5));
+            }
+            let zero_based = options.zero_based_day_of_week;
+            let range = format!(
+                "{}-{}",
+                weekday(start, zero_based),
+                weekday(end, zero_based)
+            );
+            let item = match item.split_once('/') {
+                Some((_, step)) => format!("{}/{}", range, step),
+                None => range,
+            };
+            let days = parse_item(&item, 0, 6).ok_or_else(|| parse_error(expression, 5))?;
+            items.extend(days.into_iter().map(DayOfWeekItem::Day));
+        } else {
+            let first = first_day_of_week(options.zero_based_day_of_week);
+            let days = parse_item(item, first, 7).ok_or_else(|| parse_error(expression, 5))?;
+            items.extend(
+                days.into_iter()
+                    .map(|d| DayOfWeekItem::Day(weekday(d, options.zero_based_day_of_week))),
+            );
+        }
+    }
+    items.dedup();
+    Ok(items)
+}
+
+/// Whether a day matching either day field fires rather than only a day matching both:
+/// when the dialect does and, as in Vixie cron, neither field starts with `*`, so that
+/// `*/2` with `MON` only matches the Mondays among every other day.
+pub(crate) fn matches_any_day(day_of_month: &str, day_of_week: &str, options: &Options) -> bool {
+    options.match_any_day && !day_of_month.starts_with('*') && !day_of_week.starts_with('*')
+}
+
+/// The lowest day of week number, 1 when Sunday is 1.
+pub(crate) fn first_day_of_week(zero_based: bool) -> u32 {
+    if zero_based {
+        0
+    } else {
+        1
+    }
+}
+
+// Sunday-based weekday of a day of week number, at least `first_day_of_week`.
+fn weekday(value: u32, zero_based: bool) -> u32 {
+    if zero_based {
+        value % 7
+    } else {
+        value - 1
+    }
+}
+
+/// Parses a single day of week number or one of the Quartz `L` (Saturday), `nL` and `n#k`
+/// forms.
+pub(crate) fn parse_day_of_week_item(item: &str, zero_based: bool) -> Option<DayOfWeekItem> {
+    let day = |s: &str| {
+        s.parse::<u32>()
+            .ok()
+            .filter(|d| (first_day_of_week(zero_based)..=7).contains(d))
+            .map(|d| weekday(d, zero_based))
+    };
+    if item == "L" {
+        Some(DayOfWeekItem::Day(6))
+    } else if let Some((d, nth)) = item.split_once('#') {
+        let nth = nth.parse::<u32>().ok().filter(|n| (1..=5).contains(n))?;
+        Some(DayOfWeekItem::Nth(day(d)?, nth))
+    } else if let Some(d) = item.strip_suffix('L') {
+        day(d).map(DayOfWeekItem::Last)
+    } else {
+        day(item).map(DayOfWeekItem::Day)
+    }
+}
+
+// Writes sorted values as a step, ranges of three or more values, or a list.
+pub(crate) fn format_values(values: &[u32], min: u32, max: u32, names: Option<&[&str]>) -> String {
+    if values.len() as u32 == max - min + 1 {
+        return "*".to_string();
+    }
+    let name = |v: u32| names.map_or(v.to_string(), |n| n[v as usize].to_string());
+    if values.len() >= 3 {
+        let step = values[1] - values[0];
+        if step > 1
+            && values.windows(2).all(|w| w[1] - w[0] == step)
+            && values[0] - min < step
+            && values[values.len() - 1] + step > max
+        {
+            return if values[0] == min {
+                format!("*/{}", step)
+            } else {
+                format!("{}/{}", values[0], step)
+            };
+        }
+    }
+    let mut items = Vec::new();
+    let mut i = 0;
+    while i < values.len() {
+        let mut j = i;
+        while j + 1 < values.len() && values[j + 1] == values[j] + 1 {
+            j += 1;
+        }
+        if j - i >= 2 {
+            items.push(format!("{}-{}", name(values[i]), name(values[j])));
+        } else {
+            items.extend(values[i..=j].iter().map(|v| name(*v)));
+        }
+        i = j + 1;
+    }
+    items.join(",")
+}
@@ -0,0 +1,68 @@
+use cron_descriptor::cronparser::Options;
+mod test_utils;
+use crate::test_utils::{unwrapped_description, unwrapped_description_options};
+
+#[ctor::ctor]
+fn init() {
+    rust_i18n::set_locale("ar");
+}
+
+fn description(cron_expr: &str) -> String {
+    unwrapped_description_options(cron_expr, &Options::twenty_four_hour())
+}
+
+#[test]
+fn test_dual_plurals() {
+    assert_eq!("كل دقيقة", description("* * * * *"));
+    assert_eq!("كل دقيقتين", description("*/2 * * * *"));
+    assert_eq!("كل \u{2066}5\u{2069} دقائق", description("*/5 * * * *"));
+    assert_eq!("كل \u{2066}21\u{2069} دقيقة", description("*/21 * * * *"));
+    assert_eq!("في \u{2066}09:00\u{2069}، كل يومين", description("0 9 */2 * *"));
+    assert_eq!("في \u{2066}09:00\u{2069}، كل شهرين", description("0 9 * */2 *"));
+    assert_eq!("بعد ثانية واحدة من بداية الدقيقة", description("1 * * * * *"));
+    assert_eq!(
+        "بعد \u{2066}1\u{2069} و\u{2066}2\u{2069} ثانية من بداية الدقيقة",
+        description("1,2 * * * * *")
+    );
+}
+
+#[test]
+fn test_isolated_times_and_numbers() {
+    assert_eq!("في \u{2066}9:00\u{2069} ص", unwrapped_description("0 9 * * *"));
+    assert_eq!(
+        "كل \u{2066}5\u{2069} دقائق بين \u{2066}09:00\u{2069} و\u{2066}17:55\u{2069}",
+        description("*/5 9-17 * * *")
+    );
+    assert_eq!(
+        "في \u{2066}09:00\u{2069}، قبل اليوم الأخير من الشهر بـ\u{2066}3\u{2069} أيام",
+        description("0 9 L-3 * ?")
+    );
+    assert_eq!("في \u{2066}09:00\u{2069}، في \u{2066}2025\u{2069} فقط", description("0 9 * * * 2025"));
+}
+
+#[test]
+fn test_days_and_months() {
+    assert_eq!("في \u{2066}09:00\u{2069}، من الاثنين إلى الجمعة", description("0 9 * * MON-FRI"));
+    assert_eq!(
+        "في \u{2066}09:00\u{2069}، يوم الثلاثاء، الأربعاء والجمعة فقط",
+        description("0 9 * * 2,3,5")
+    );
+    assert_eq!(
+        "في \u{2066}09:00\u{2069}، في يوم الأربعاء الثاني من الشهر",
+        description("0 9 ? * WED#2")
+    );
+    assert_eq!("في \u{2066}09:00\u{2069}، كل شهرين، بدءًا من مارس", description("0 9 * 3/2 *"));
+}
+
+#[test]
+fn test_wrapped_ranges() {
+    assert_eq!("في \u{2066}09:00\u{2069}، من نوفمبر إلى فبراير", description("0 9 * NOV-FEB *"));
+    assert_eq!(
+        "في \u{2066}09:00\u{2069}، في الفترة من نوفمبر إلى فبراير ويونيو فقط",
+        description("0 9 * NOV-FEB,JUN *")
+    );
+    assert_eq!(
+        "بعد \u{2066}50\u{2069} إلى \u{2066}59\u{2069} و\u{2066}00\u{2069} إلى \u{2066}10\u{2069} دقيقة من بداية الساعة",
+        description("50-10 * * * *")
+    );
+}
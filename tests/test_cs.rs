@@ -0,0 +1,57 @@
+use cron_descriptor::cronparser::Options;
+mod test_utils;
+use crate::test_utils::unwrapped_description_options;
+
+#[ctor::ctor]
+fn init() {
+    rust_i18n::set_locale("cs");
+}
+
+fn description(cron_expr: &str) -> String {
+    unwrapped_description_options(cron_expr, &Options::twenty_four_hour())
+}
+
+#[test]
+fn test_every_minute() {
+    assert_eq!("Každou minutu", description("* * * * *"));
+    assert_eq!("Každou sekundu", description("*/1 * * * * *"));
+}
+
+#[test]
+fn test_plurals() {
+    assert_eq!("Co 2 minuty", description("*/2 * * * *"));
+    assert_eq!("Co 5 minut", description("*/5 * * * *"));
+    assert_eq!("Co 22 minut", description("*/22 * * * *"));
+    assert_eq!("1 a 2 sekundy po celé minutě", description("1,2 * * * * *"));
+    assert_eq!("V 09:00, 1 den před posledním dnem měsíce", description("0 9 L-1 * ?"));
+    assert_eq!("V 09:00, 3 dny před posledním dnem měsíce", description("0 9 L-3 * ?"));
+    assert_eq!("V 09:00, 5 dní před posledním dnem měsíce", description("0 9 L-5 * ?"));
+}
+
+#[test]
+fn test_day_of_week_cases() {
+    assert_eq!("V 09:00, od pondělí do pátku", description("0 9 * * MON-FRI"));
+    assert_eq!("V 09:00, pouze v úterý, ve středu a v pátek", description("0 9 * * 2,3,5"));
+    assert_eq!("V 09:00, co 2 dny v týdnu, počínaje středou", description("0 9 * * 3/2"));
+}
+
+#[test]
+fn test_nth_and_last_day_of_week() {
+    assert_eq!("V 09:00, ve druhé pondělí v měsíci", description("0 9 ? * MON#2"));
+    assert_eq!("V 09:00, ve druhou středu v měsíci", description("0 9 ? * WED#2"));
+    assert_eq!("V 09:00, v poslední neděli v měsíci", description("0 9 ? * 0L"));
+}
+
+#[test]
+fn test_month_cases() {
+    assert_eq!("V 09:00, od ledna do března", description("0 9 * JAN-MAR *"));
+    assert_eq!("V 09:00, pouze v lednu, v březnu a v červenci", description("0 9 * 1,3,7 *"));
+    assert_eq!("V 09:00, co 2 měsíce, počínaje březnem", description("0 9 * 3/2 *"));
+}
+
+#[test]
+fn test_wrapped_ranges() {
+    assert_eq!("V 50–59 a 00–10 minut po celé hodině", description("50-10 * * * *"));
+    assert_eq!("V 05, 50–59 a 00–10 minut po celé hodině", description("5,50-10 * * * *"));
+    assert_eq!("V 00:00, v den 28–31 a 1–3 v měsíci", description("0 0 28-3 * ?"));
+}
@@ -0,0 +1,91 @@
+use cron_descriptor::cronparser::{Clock, Options, Verbosity};
+mod test_utils;
+use crate::test_utils::{unwrapped_description, unwrapped_description_options};
+
+#[ctor::ctor]
+fn init() {
+    rust_i18n::set_locale("de");
+}
+
+#[test]
+fn test_every_minute() {
+    assert_eq!("Jede Minute", unwrapped_description("* * * * *"));
+    assert_eq!("Jede Sekunde", unwrapped_description("*/1 * * * * *"));
+    assert_eq!("Jede Minute zwischen 11:00 und 11:10", unwrapped_description("0-10 11 * * *"));
+    assert_eq!("Jede Minute, um 01:00", unwrapped_description("* 1 * * *"));
+}
+
+#[test]
+fn test_twenty_four_hour_times() {
+    assert_eq!("Um 11:30", unwrapped_description("30 11 * * *"));
+    assert_eq!("Um 23:00, Montag bis Freitag", unwrapped_description("0 23 ? * MON-FRI"));
+    assert_eq!("Um 14:30 und 16:30", unwrapped_description("30 14,16 * * *"));
+    assert_eq!("Zwischen 00:00 und 02:00", unwrapped_description("0 0-2 * * *"));
+    assert_eq!("Alle 5 Minuten zwischen 09:00 und 17:55", unwrapped_description("*/5 9-17 * * *"));
+}
+
+#[test]
+fn test_plurals() {
+    assert_eq!("1 Sekunde nach Beginn der Minute", unwrapped_description("1 * * * * *"));
+    assert_eq!("1 und 2 Sekunden nach Beginn der Minute", unwrapped_description("1,2 * * * * *"));
+    assert_eq!("Alle 5 Minuten", unwrapped_description("*/5 * * * *"));
+    assert_eq!("Alle 2 Stunden", unwrapped_description("0 */2 * * *"));
+    assert_eq!("Um 00:00, alle 2 Tage", unwrapped_description("0 0 */2 * *"));
+    assert_eq!("Um 09:00, 1 Tag vor dem letzten Tag des Monats", unwrapped_description("0 9 L-1 * ?"));
+    assert_eq!("Um 09:00, 2 Tage vor dem letzten Tag des Monats", unwrapped_description("0 9 L-2 * ?"));
+}
+
+#[test]
+fn test_days_of_week() {
+    assert_eq!("Um 09:00, Montag bis Freitag", unwrapped_description("0 9 * * MON-FRI"));
+    assert_eq!("Um 09:00, nur am Dienstag, Mittwoch und Freitag", unwrapped_description("0 9 * * 2,3,5"));
+    assert_eq!("Um 09:00, nur am Sonntag", unwrapped_description("0 9 * * 0"));
+    assert_eq!("Um 09:00, alle 2 Tage innerhalb der Woche, beginnend am Dienstag", unwrapped_description("0 9 * * 2/2"));
+    assert_eq!("Um 09:00, am Tag 1 des Monats, oder am Dienstag", unwrapped_description("0 9 1 * TUE"));
+}
+
+#[test]
+fn test_nth_and_last_days() {
+    assert_eq!("Um 09:00, am ersten Montag des Monats", unwrapped_description("0 9 ? * MON#1"));
+    assert_eq!("Um 09:00, am ersten Sonntag des Monats", unwrapped_description("0 9 ? * SUN#1"));
+    assert_eq!("Um 09:00, am dritten Freitag und ersten Montag des Monats", unwrapped_description("0 9 ? * FRI#3,MON#1"));
+    assert_eq!("Um 09:00, am letzten Freitag des Monats", unwrapped_description("0 9 ? * 5L"));
+    assert_eq!("Um 09:00, am letzten Tag des Monats", unwrapped_description("0 9 L * ?"));
+    assert_eq!("Um 09:00, am letzten Werktag des Monats", unwrapped_description("0 9 LW * ?"));
+    assert_eq!("Um 09:00, am nächstgelegenen Werktag zum 15. des Monats", unwrapped_description("0 9 15W * ?"));
+}
+
+#[test]
+fn test_months_and_years() {
+    assert_eq!("Um 09:00, Januar bis März", unwrapped_description("0 9 * JAN-MAR *"));
+    assert_eq!("Um 09:00, nur im Januar, März und Juli", unwrapped_description("0 9 * 1,3,7 *"));
+    assert_eq!("Um 09:00, alle 2 Monate, beginnend im März", unwrapped_description("0 9 * 3/2 *"));
+    assert_eq!("Um 09:00, 2025 bis 2027", unwrapped_description("0 9 * * * 2025-2027"));
+}
+
+#[test]
+fn test_ordinal_days() {
+    let ordinal = Options { ordinal_days: true, ..Options::options() };
+    assert_eq!("Um 00:00, am 1. und 15. des Monats", unwrapped_description_options("0 0 1,15 * *", &ordinal));
+    assert_eq!("Um 09:00, vom 1. bis 10. des Monats", unwrapped_description_options("0 9 1-10 * *", &ordinal));
+    assert_eq!("Um 09:00, am 1. Montag und 3. Freitag des Monats", unwrapped_description_options("0 9 ? * MON#1,FRI#3", &ordinal));
+}
+
+#[test]
+fn test_verbosity() {
+    let verbose = Options { verbosity: Verbosity::Verbose, ..Options::options() };
+    let compact = Options { verbosity: Verbosity::Compact, ..Options::options() };
+    let abbreviated = Options { abbreviated: true, ..compact };
+    assert_eq!("Um 09:00, Montag bis Freitag, jeden Monat, jedes Jahr", unwrapped_description_options("0 9 * * MON-FRI", &verbose));
+    assert_eq!("Mo–Fr 09:00", unwrapped_description_options("0 9 * * MON-FRI", &compact));
+    assert_eq!("Werktags 09:00–17:00, alle 15 Min.", unwrapped_description_options("*/15 9-17 * * 1-5", &abbreviated));
+}
+
+#[test]
+fn test_time_formatting() {
+    let twelve_hour = Options { clock: Clock::TwelveHour, ..Options::options() };
+    let spoken = Options { noon_and_midnight: true, ..Options::options() };
+    assert_eq!("Um 9:30 PM", unwrapped_description_options("30 21 * * *", &twelve_hour));
+    assert_eq!("Um 12 Uhr mittags", unwrapped_description_options("0 12 * * *", &spoken));
+    assert_eq!("Zwischen Mitternacht und 02:00", unwrapped_description_options("0 0-2 * * *", &spoken));
+}
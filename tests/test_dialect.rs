@@ -0,0 +1,190 @@
+use cron_descriptor::cronparser::cron_expression_descriptor::ParseException;
+use cron_descriptor::dialect::{convert, Conversion, Dialect, Loss};
+
+fn converted(cron_expr: &str, from: Dialect, to: Dialect) -> String {
+    let conversion = convert(cron_expr, from, to).unwrap();
+    assert_eq!(Vec::<Loss>::new(), conversion.losses);
+    conversion.expression
+}
+
+#[test]
+fn test_unix_to_quartz() {
+    assert_eq!(
+        "0 0 9 ? * 2-6",
+        converted("0 9 * * 1-5", Dialect::Unix, Dialect::Quartz)
+    );
+    assert_eq!(
+        "0 */15 * * * ?",
+        converted("*/15 * * * *", Dialect::Unix, Dialect::Quartz)
+    );
+    assert_eq!(
+        "0 0 0 1 * ?",
+        converted("0 0 1 * *", Dialect::Unix, Dialect::Quartz)
+    );
+}
+
+#[test]
+fn test_day_of_week_renumbering() {
+    assert_eq!(
+        "0 0 9 ? * MON-FRI",
+        converted("0 9 * * MON-FRI", Dialect::Vixie, Dialect::Quartz)
+    );
+    assert_eq!(
+        "0 0 9 ? * 1,6,7",
+        converted("0 9 * * 5-7", Dialect::Vixie, Dialect::Quartz)
+    );
+    assert_eq!(
+        "0 0 9 ? * */2",
+        converted("0 9 * * */2", Dialect::Vixie, Dialect::Quartz)
+    );
+    assert_eq!(
+        "0 9 * * 1-5",
+        converted("0 0 9 ? * 2-6", Dialect::Quartz, Dialect::Vixie)
+    );
+    assert_eq!(
+        "0 12 * * 0",
+        converted("0 0 12 ? * 1", Dialect::Quartz, Dialect::Unix)
+    );
+    assert_eq!(
+        "0 0 9 * * 5L",
+        converted("0 0 9 ? * 6L", Dialect::Quartz, Dialect::Spring)
+    );
+    assert_eq!(
+        "0 0 9 ? * 3#2",
+        converted("0 0 9 * * 2#2", Dialect::Spring, Dialect::Quartz)
+    );
+}
+
+#[test]
+fn test_names_in_unix() {
+    assert_eq!(
+        "0 12 * 1,2 0",
+        converted("0 12 * JAN,FEB SUN", Dialect::Vixie, Dialect::Unix)
+    );
+}
+
+#[test]
+fn test_vixie_macros() {
+    assert_eq!(
+        "0 0 0 ? * 1",
+        converted("@weekly", Dialect::Vixie, Dialect::Quartz)
+    );
+    assert_eq!(
+        "0 0 * * ? *",
+        converted("@daily", Dialect::Vixie, Dialect::EventBridge)
+    );
+    assert!(convert("@reboot", Dialect::Vixie, Dialect::Quartz).is_err());
+}
+
+#[test]
+fn test_event_bridge() {
+    assert_eq!(
+        "0 12 * * MON-FRI",
+        converted(
+            "cron(0 12 ? * MON-FRI *)",
+            Dialect::EventBridge,
+            Dialect::Vixie
+        )
+    );
+    assert_eq!(
+        "0 12 * * ? *",
+        converted("0 0 12 * * ?", Dialect::Quartz, Dialect::EventBridge)
+    );
+    assert_eq!(
+        "0 0 10 L * ? 2027",
+        converted("0 10 L * ? 2027", Dialect::EventBridge, Dialect::Quartz)
+    );
+}
+
+#[test]
+fn test_losses() {
+    assert_eq!(
+        Conversion {
+            expression: "0 9 * * *".to_string(),
+            losses: vec![
+                Loss::Seconds("30".to_string()),
+                Loss::DayOfMonth("L".to_string()),
+                Loss::Year("2027".to_string())
+            ]
+        },
+        convert("30 0 9 L * ? 2027", Dialect::Quartz, Dialect::Vixie).unwrap()
+    );
+    assert_eq!(
+        Conversion {
+            expression: "0 9 * * MON".to_string(),
+            losses: vec![Loss::DayOfWeek("MON#2".to_string())]
+        },
+        convert("0 0 9 ? * MON#2", Dialect::Quartz, Dialect::Vixie).unwrap()
+    );
+    assert_eq!(
+        Conversion {
+            expression: "0 9 15 * *".to_string(),
+            losses: vec![Loss::DayOfMonth("LW".to_string())]
+        },
+        convert("0 0 9 LW,15 * ?", Dialect::Spring, Dialect::Unix).unwrap()
+    );
+}
+
+#[test]
+fn test_day_combination_losses() {
+    assert_eq!(
+        Conversion {
+            expression: "0 0 0 1,15 * ?".to_string(),
+            losses: vec![Loss::DayCombination]
+        },
+        convert("0 0 1,15 * MON", Dialect::Vixie, Dialect::Quartz).unwrap()
+    );
+    assert_eq!(
+        Conversion {
+            expression: "0 0 0 1,15 * MON".to_string(),
+            losses: vec![Loss::DayCombination]
+        },
+        convert("0 0 1,15 * MON", Dialect::Vixie, Dialect::Spring).unwrap()
+    );
+    assert_eq!(
+        "0 0 1,15 * 1",
+        converted("0 0 1,15 * MON", Dialect::Vixie, Dialect::Unix)
+    );
+}
+
+#[test]
+fn test_invalid_for_dialect() {
+    assert_eq!(
+        Err(ParseException {
+            s: "0 0 12 * * *".to_string(),
+            error_offset: 5
+        }),
+        convert("0 0 12 * * *", Dialect::Quartz, Dialect::Vixie)
+    );
+    assert!(convert("0 0 12 1 * MON", Dialect::Quartz, Dialect::Vixie).is_err());
+    assert!(convert("0 12 ? * *", Dialect::Vixie, Dialect::Quartz).is_err());
+    assert!(convert("0 0 12 * * ?", Dialect::Vixie, Dialect::Quartz).is_err());
+    assert!(convert("0 12 * * *", Dialect::Spring, Dialect::Quartz).is_err());
+    assert!(convert("0 12 * * 9", Dialect::Unix, Dialect::Quartz).is_err());
+}
+
+#[test]
+fn test_wrap_around_ranges() {
+    assert_eq!(
+        "0 0 22-2 ? * FRI-MON",
+        converted("0 0 22-2 ? * FRI-MON", Dialect::Quartz, Dialect::Quartz)
+    );
+    assert_eq!(
+        "0 0-2,22,23 * 1,2,11,12 0,1,5,6",
+        converted("0 0 22-2 ? NOV-FEB FRI-MON", Dialect::Quartz, Dialect::Vixie)
+    );
+    assert_eq!(
+        "0 0-2,22,23 ? * 1,2,6,7 *",
+        converted("0 0 22-2 ? * 6-2", Dialect::Quartz, Dialect::EventBridge)
+    );
+    assert_eq!(
+        Err(ParseException {
+            s: "0 22-2 * * *".to_string(),
+            error_offset: 2
+        }),
+        convert("0 22-2 * * *", Dialect::Vixie, Dialect::Quartz)
+    );
+    assert!(convert("0 9 * * FRI-MON", Dialect::Vixie, Dialect::Quartz).is_err());
+    assert!(convert("0 0 9 ? * FRI-MON", Dialect::Spring, Dialect::Quartz).is_err());
+    assert!(convert("0 9 ? DEC-JAN * *", Dialect::EventBridge, Dialect::Quartz).is_err());
+}
@@ -0,0 +1,137 @@
+use std::fs;
+use std::path::Path;
+
+use cron_descriptor::cronparser::Options;
+use cron_descriptor::discovery::{scan_dir, scan_str, DiscoveredSchedule, Source};
+
+const CRON_JOBS: &str = "\
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: settings
+---
+apiVersion: batch/v1
+kind: CronJob
+metadata:
+  name: report
+spec:
+  schedule: \"30 2 * * 1-5\"
+  timeZone: Europe/Lisbon
+  jobTemplate: {}
+---
+apiVersion: batch/v1
+kind: CronJob
+metadata:
+  name: cleanup
+spec:
+  schedule: \"@hourly\"
+";
+
+const WORKFLOW: &str = "\
+name: nightly
+on:
+  push:
+    branches: [main]
+  schedule:
+    - cron: '0 3 * * *'
+    - cron: '*/15 * * * *'
+jobs: {}
+";
+
+fn scanned(file: &str, content: &str) -> Vec<DiscoveredSchedule> {
+    scan_str(Path::new(file), content, &Options::options(), "en").unwrap()
+}
+
+#[test]
+fn test_cron_jobs() {
+    let found = scanned("deploy/jobs.yaml", CRON_JOBS);
+    assert_eq!(2, found.len());
+    assert_eq!(
+        DiscoveredSchedule {
+            file: "deploy/jobs.yaml".into(),
+            document: 1,
+            path: "spec.schedule".to_string(),
+            source: Source::KubernetesCronJob,
+            expression: "30 2 * * 1-5".to_string(),
+            time_zone: Some("Europe/Lisbon".to_string()),
+            description: Ok("At 2:30 AM, Monday through Friday".to_string()),
+        },
+        found[0]
+    );
+    assert_eq!(2, found[1].document);
+    assert_eq!(None, found[1].time_zone);
+    assert_eq!(Ok("Every hour".to_string()), found[1].description);
+}
+
+#[test]
+fn test_cron_job_list() {
+    let list = "\
+apiVersion: v1
+kind: List
+items:
+  - kind: Service
+  - kind: CronJob
+    spec:
+      schedule: 0 0 1 * *
+";
+    let found = scanned("list.yaml", list);
+    assert_eq!(1, found.len());
+    assert_eq!("items[1].spec.schedule", found[0].path);
+    assert_eq!("0 0 1 * *", found[0].expression);
+}
+
+#[test]
+fn test_workflow() {
+    let found = scanned(".github/workflows/nightly.yml", WORKFLOW);
+    assert_eq!(2, found.len());
+    assert_eq!("on.schedule[0].cron", found[0].path);
+    assert_eq!(Source::GitHubActions, found[0].source);
+    assert_eq!(Some("UTC".to_string()), found[0].time_zone);
+    assert_eq!(Ok("At 3:00 AM".to_string()), found[0].description);
+    assert_eq!("on.schedule[1].cron", found[1].path);
+    assert_eq!(Ok("Every 15 minutes".to_string()), found[1].description);
+}
+
+#[test]
+fn test_invalid_schedule() {
+    let found = scanned("bad.yaml", "kind: CronJob\nspec:\n  schedule: '0 0 * *'\n");
+    assert_eq!(1, found.len());
+    assert!(found[0].description.is_err());
+}
+
+#[test]
+fn test_not_a_schedule() {
+    assert!(scanned("other.yaml", "kind: Deployment\nspec:\n  replicas: 2\n").is_empty());
+    assert!(scanned("on.yml", "on: push\n").is_empty());
+    assert!(scan_str(Path::new("bad.yaml"), "a: [", &Options::options(), "en").is_err());
+}
+
+#[test]
+fn test_scan_dir() {
+    let root = std::env::temp_dir().join(format!("cron-discovery-{}", std::process::id()));
+    let workflows = root.join(".github").join("workflows");
+    fs::create_dir_all(&workflows).unwrap();
+    fs::write(root.join("jobs.yaml"), CRON_JOBS).unwrap();
+    fs::write(workflows.join("nightly.yml"), WORKFLOW).unwrap();
+    fs::write(root.join("broken.yml"), "a: [").unwrap();
+    fs::write(root.join("notes.txt"), WORKFLOW).unwrap();
+
+    let report = scan_dir(&root, &Options::options(), "en");
+    fs::remove_dir_all(&root).unwrap();
+
+    let found: Vec<(&Path, &str)> = report
+        .schedules
+        .iter()
+        .map(|s| (s.file.strip_prefix(&root).unwrap(), s.expression.as_str()))
+        .collect();
+    assert_eq!(
+        vec![
+            (Path::new(".github/workflows/nightly.yml"), "0 3 * * *"),
+            (Path::new(".github/workflows/nightly.yml"), "*/15 * * * *"),
+            (Path::new("jobs.yaml"), "30 2 * * 1-5"),
+            (Path::new("jobs.yaml"), "@hourly"),
+        ],
+        found
+    );
+    assert_eq!(1, report.errors.len());
+}
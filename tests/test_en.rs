@@ -2,7 +2,7 @@ use cron_descriptor;
 extern crate strfmt;
 
 use cron_descriptor::cronparser::cron_expression_descriptor;
-use cron_descriptor::cronparser::Options;
+use cron_descriptor::cronparser::{Clock, NameLocale, Options, Verbosity};
 use cron_descriptor::cronparser::cron_expression_descriptor::ParseException;
 mod test_utils;
 use crate::test_utils::unwrapped_description_options;
@@ -368,15 +368,15 @@ fn test_day_of_week_name() {
 #[test]
 fn test_day_of_week_range() {
     assert_eq!(
-        "Every 5 minutes, at 3:00 PM, Monday through Friday",
+        "Every 5 minutes between 3:00 PM and 3:55 PM, Monday through Friday",
         unwrapped_description("*/5 15 * * MON-FRI")
     );
     assert_eq!(
-        "Every 5 minutes, at 3:00 PM, Sunday through Saturday",
+        "Every 5 minutes between 3:00 PM and 3:55 PM, Sunday through Saturday",
         unwrapped_description("*/5 15 * * 0-6")
     );
     assert_eq!(
-        "Every 5 minutes, at 3:00 PM, Saturday through Sunday",
+        "Every 5 minutes between 3:00 PM and 3:55 PM, Saturday through Sunday",
         unwrapped_description("*/5 15 * * 6-7")
     );
 }
@@ -421,6 +421,98 @@ fn test_last_day_of_the_month() {
     );
 }
 
+#[test]
+fn test_last_day_of_the_month_in_lists() {
+    assert_eq!(
+        "At 12:00 AM, on day 15 of the month and on the last day of the month",
+        unwrapped_description("0 0 L,15 * *")
+    );
+    assert_eq!(
+        "At 12:00 AM, on day 1 and 15 of the month and 2 days before the last day of the month",
+        unwrapped_description("0 0 1,15,L-2 * *")
+    );
+    assert_eq!(
+        "At 12:00 AM, on the weekday nearest day 10 of the month and on the last weekday of the month",
+        unwrapped_description("0 0 10W,LW * *")
+    );
+}
+
+#[test]
+fn test_days_before_the_last_day_of_the_month() {
+    assert_eq!(
+        "At 9:00 AM, 3 days before the last day of the month",
+        unwrapped_description("0 9 L-3 * ?")
+    );
+    assert_eq!(
+        "At 9:00 AM, 1 day before the last day of the month",
+        unwrapped_description("0 9 L-1 * ?")
+    );
+}
+
+#[test]
+fn test_weekday_nearest_day() {
+    assert_eq!(
+        "At 9:00 AM, on the weekday nearest day 15 of the month",
+        unwrapped_description("0 9 15W * ?")
+    );
+    assert_eq!(
+        "At 9:00 AM, on the weekday nearest day 3 of the month",
+        unwrapped_description("0 9 W3 * ?")
+    );
+    assert_eq!(
+        "At 9:00 AM, on the first weekday of the month",
+        unwrapped_description("0 9 1W * ?")
+    );
+    assert_eq!(
+        "At 9:00 AM, on the last weekday of the month",
+        unwrapped_description("0 9 LW * ?")
+    );
+}
+
+#[test]
+fn test_invalid_last_and_weekday_days() {
+    for (expression, error_offset) in [
+        ("0 9 L-40 * ?", 3),
+        ("0 9 L-0 * ?", 3),
+        ("0 9 32W * ?", 3),
+        ("0 9 L-3,LX * ?", 3),
+        ("0 9 ? * 8L", 5),
+        ("0 9 ? * 2#6", 5),
+        ("0 9 ? * 2#0", 5),
+        ("0 9 32 * ?", 3),
+        ("0 9 0-5 * ?", 3),
+        ("0 0 ? * 8", 5),
+        ("0 0 ? * 1-9", 5),
+        ("0 0 ? * FOO", 5),
+    ] {
+        assert_eq!(
+            Err(ParseException {
+                s: expression.to_string(),
+                error_offset
+            }),
+            cron_expression_descriptor::get_description_cron(expression)
+        );
+    }
+}
+
+#[test]
+fn test_invalid_day_of_week_zero_when_one_based() {
+    let options = Options {
+        zero_based_day_of_week: false,
+        ..Options::options()
+    };
+    for expression in ["0 0 ? * 0", "0 0 ? * 0-3", "0 0 ? * 5-0", "0 0 ? * 0#2", "0 0 ? * 0L"] {
+        assert_eq!(
+            Err(ParseException {
+                s: expression.to_string(),
+                error_offset: 5
+            }),
+            cron_expression_descriptor::get_description_cron_options(expression, &options)
+        );
+        assert!(cron_descriptor::schedule::parse(expression, &options).is_err());
+    }
+}
+
 #[test]
 fn test_time_of_day_with_seconds() {
     assert_eq!(
@@ -508,7 +600,7 @@ fn test_every_past_the_hour() {
 #[test]
 fn test_every_xminute_past_the_hour_with_interval() {
     assert_eq!(
-        "Every 2 minutes, minutes 00 through 30 past the hour, at 5:00 PM, Monday through Friday",
+        "Every 2 minutes between 5:00 PM and 5:30 PM, Monday through Friday",
         unwrapped_description("0 0-30/2 17 ? * MON-FRI")
     );
 }
@@ -563,7 +655,7 @@ fn test_issue26() {
         unwrapped_description("5,10 * * * *")
     );
     assert_eq!(
-        "At 05 and 10 minutes past the hour, at 12:00 AM",
+        "At 12:05 AM and 12:10 AM",
         unwrapped_description("5,10 0 * * *")
     );
     assert_eq!(
@@ -571,7 +663,7 @@ fn test_issue26() {
         unwrapped_description("5,10 * 2 * *")
     );
     assert_eq!(
-        "Every 10 minutes, on day 2 of the month",
+        "Every 10 minutes, starting at 5 minutes past the hour, on day 2 of the month",
         unwrapped_description("5/10 * 2 * *")
     );
 
@@ -580,7 +672,7 @@ fn test_issue26() {
         unwrapped_description("5,6 0 * * * *")
     );
     assert_eq!(
-        "At 5 and 6 seconds past the minute, at 1:00 AM",
+        "At 1:00:05 AM and 1:00:06 AM",
         unwrapped_description("5,6 0 1 * * *")
     );
     assert_eq!(
@@ -595,3 +687,536 @@ fn test_issue26() {
 // use rust_i18n::set_locale;
 // i18n!("locales");
 // rust_i18n::set_locale("es");
+
+#[test]
+fn test_month_names() {
+    test_utils::assert_month_names_like_numbers();
+}
+
+#[test]
+fn test_day_of_week_names() {
+    test_utils::assert_day_of_week_names_like_numbers();
+}
+
+#[test]
+fn test_names_in_any_case() {
+    assert_eq!("At 9:00 AM, only in December", unwrapped_description("0 9 * DEC *"));
+    assert_eq!(
+        "At 9:00 AM, only in January and March through May",
+        unwrapped_description("0 9 * jan,MAR-May *")
+    );
+    assert_eq!(
+        "At 9:00 AM, only on Saturday and Sunday",
+        unwrapped_description("0 9 * * sat,SUN")
+    );
+    assert_eq!(
+        "At 9:00 AM, Monday through Friday",
+        unwrapped_description("0 9 * * Monday-Friday")
+    );
+    assert_eq!(
+        "At 9:00 AM, on the last Friday of the month",
+        unwrapped_description("0 9 ? * FRIL")
+    );
+    assert_eq!(
+        "At 9:00 AM, on the second Monday of the month",
+        unwrapped_description("0 9 ? * mon#2")
+    );
+}
+
+#[test]
+fn test_offset_steps() {
+    assert_eq!(
+        "Every 15 minutes, starting at 5 minutes past the hour",
+        unwrapped_description("5/15 * * * *")
+    );
+    assert_eq!(
+        "Every 10 seconds, starting at 3 seconds past the minute",
+        unwrapped_description("3/10 * * * * *")
+    );
+    assert_eq!(
+        "Every 3 hours, starting at 5:00 AM",
+        unwrapped_description("0 5/3 * * *")
+    );
+    assert_eq!(
+        "At 9:00 AM, every 10 days, starting on day 5 of the month",
+        unwrapped_description("0 9 5/10 * *")
+    );
+    assert_eq!(
+        "At 9:00 AM, every 2 months, starting in March",
+        unwrapped_description("0 9 * 3/2 *")
+    );
+    assert_eq!(
+        "At 9:00 AM, every 2 days of the week, starting on Tuesday",
+        unwrapped_description("0 9 ? * 2/2")
+    );
+    assert_eq!(
+        "At 9:00 AM, every 2 years, starting in 2025",
+        unwrapped_description("0 0 9 * * ? 2025/2")
+    );
+    assert_eq!("Every 15 minutes", unwrapped_description("0/15 * * * *"));
+    assert_eq!(
+        "At 9:00 AM, every 2 months",
+        unwrapped_description("0 9 * 1/2 *")
+    );
+}
+
+#[test]
+fn test_list_of_occurrences_in_the_month() {
+    assert_eq!(
+        "At 9:00 AM, on the first Tuesday and the third Thursday of the month",
+        unwrapped_description("0 9 ? * TUE#1,THU#3")
+    );
+    assert_eq!(
+        "At 9:00 AM, on the last Monday and the last Friday of the month",
+        unwrapped_description("0 9 ? * 1L,5L")
+    );
+    assert_eq!(
+        "At 9:00 AM, on the second Saturday, the second Sunday and the last Friday of the month",
+        unwrapped_description("0 9 ? * SAT#2,SUN#2,FRIL")
+    );
+    assert_eq!(
+        "At 9:00 AM, on Monday through Wednesday and the third Friday of the month",
+        unwrapped_description("0 9 ? * MON-WED,FRI#3")
+    );
+    assert_eq!(
+        Err(ParseException {
+            s: "0 9 ? * MON#1,FRI#6".to_string(),
+            error_offset: 5
+        }),
+        cron_expression_descriptor::get_description_cron("0 9 ? * MON#1,FRI#6")
+    );
+}
+
+#[test]
+fn test_wrap_around_ranges() {
+    assert_eq!(
+        "Between 10:00 PM and 2:00 AM overnight",
+        unwrapped_description("0 22-2 * * *")
+    );
+    assert_eq!(
+        "Between 12:00 AM and 2:00 AM and between 10:00 PM and 11:00 PM, only on Friday",
+        unwrapped_description("0 22-2 * * FRI")
+    );
+    assert_eq!(
+        "At 30 minutes past the hour, between 12:00 AM and 1:00 AM and at 11:00 PM, on day 1 of the month",
+        unwrapped_description("30 23-1 1 * *")
+    );
+    assert_eq!(
+        "At 9:00 AM, Friday through Monday",
+        unwrapped_description("0 9 * * FRI-MON")
+    );
+    assert_eq!(
+        "At 9:00 AM, November through February",
+        unwrapped_description("0 9 * NOV-FEB *")
+    );
+    assert_eq!(
+        "At 12:00 AM, on day 5 through 31 and 1 through 3 of the month",
+        unwrapped_description("0 0 5-3 * *")
+    );
+    assert_eq!(
+        "At 12:00 AM, on day 25, 27, 29, 31, 2 and 4 of the month",
+        unwrapped_description("0 0 25-5/2 * *")
+    );
+    assert_eq!(
+        "At 50 through 59 and 00 through 10 minutes past the hour, at 9:00 AM",
+        unwrapped_description("0 50-10 9 * * *")
+    );
+    assert_eq!(
+        Err(ParseException {
+            s: "0 0 12 * * ? 2030-2025".to_string(),
+            error_offset: 6
+        }),
+        cron_expression_descriptor::get_description_cron("0 0 12 * * ? 2030-2025")
+    );
+}
+
+#[test]
+fn test_day_of_month_or_day_of_week() {
+    assert_eq!(
+        "At 12:00 AM, on day 1 and 15 of the month, or on Monday",
+        unwrapped_description("0 0 1,15 * MON")
+    );
+    assert_eq!(
+        "At 9:00 AM, on the last day of the month, or Monday through Friday",
+        unwrapped_description("0 9 L * MON-FRI")
+    );
+    assert_eq!(
+        "At 9:00 AM, on day 1 of the month, or on the second Monday of the month",
+        unwrapped_description("0 9 1 * MON#2")
+    );
+    let both = Options {
+        match_any_day: false,
+        ..Options::options()
+    };
+    assert_eq!(
+        "At 9:00 AM, on day 13 of the month, only on Friday",
+        unwrapped_description_options("0 9 13 * FRI", &both)
+    );
+
+    // As in Vixie cron, a field starting with `*` makes the days match both fields.
+    assert_eq!(
+        "At 12:00 AM, every 2 days, only on Monday",
+        unwrapped_description("0 0 */2 * MON")
+    );
+    assert_eq!(
+        "At 12:00 AM, between day 1 and 5 of the month, every 2 days of the week",
+        unwrapped_description("0 0 1-5 * */2")
+    );
+    let options = Options::options();
+    let schedule = cron_descriptor::schedule::parse("0 0 */2 * MON", &options).unwrap();
+    assert!(schedule.matches_date(2025, 6, 9));
+    assert!(!schedule.matches_date(2025, 6, 2));
+    assert!(!schedule.matches_date(2025, 6, 3));
+    let schedule = cron_descriptor::schedule::parse("0 0 1-5 * */2", &options).unwrap();
+    assert!(schedule.matches_date(2025, 6, 1));
+    assert!(schedule.matches_date(2025, 6, 3));
+    assert!(!schedule.matches_date(2025, 6, 2));
+    assert!(!schedule.matches_date(2025, 6, 8));
+    let schedule = cron_descriptor::schedule::parse("0 0 1,15 * MON", &options).unwrap();
+    assert!(schedule.matches_date(2025, 6, 2));
+    assert!(schedule.matches_date(2025, 6, 15));
+}
+
+#[test]
+fn test_listed_times() {
+    assert_eq!(
+        "At 9:00 AM, 9:30 AM, 5:00 PM and 5:30 PM",
+        unwrapped_description("0,30 9,17 * * *")
+    );
+    assert_eq!(
+        "At 9:15 AM, 9:45 AM, 5:15 PM and 5:45 PM, Monday through Friday",
+        unwrapped_description("45,15 17,9 * * MON-FRI")
+    );
+    assert_eq!(
+        "At 00, 15, 30 and 45 minutes past the hour, at 9:00 AM, 12:00 PM and 5:00 PM",
+        unwrapped_description("0,15,30,45 9,12,17 * * *")
+    );
+    let options = Options {
+        max_listed_times: 3,
+        ..Options::options()
+    };
+    assert_eq!(
+        "At 00 and 30 minutes past the hour, at 9:00 AM and 5:00 PM",
+        unwrapped_description_options("0,30 9,17 * * *", &options)
+    );
+}
+
+#[test]
+fn test_steps_within_windows() {
+    assert_eq!(
+        "Every 5 minutes between 9:00 AM and 5:55 PM, Monday through Friday",
+        unwrapped_description("*/5 9-17 * * MON-FRI")
+    );
+    assert_eq!(
+        "Every 20 minutes between 9:00 AM and 5:40 PM",
+        unwrapped_description("0/20 9-17 * * *")
+    );
+    assert_eq!(
+        "Every 10 minutes between 9:10 AM and 9:40 AM",
+        unwrapped_description("10-40/10 9 * * *")
+    );
+    assert_eq!(
+        "Every minute between 9:00 AM and 9:30 AM and between 5:00 PM and 5:30 PM",
+        unwrapped_description("0-30 9,17 * * *")
+    );
+    assert_eq!(
+        "Every 10 seconds between 9:00:00 AM and 5:59:50 PM",
+        unwrapped_description("*/10 * 9-17 * * *")
+    );
+    assert_eq!(
+        "Every 15 seconds between 9:15:00 AM and 9:20:45 AM",
+        unwrapped_description("*/15 15-20 9 * * *")
+    );
+    assert_eq!(
+        "At 30 minutes past the hour, every 3 hours, between 10:00 AM and 7:00 PM",
+        unwrapped_description("30 10-20/3 * * *")
+    );
+    assert_eq!(
+        "At 12:00 AM, every 2 days of the week, Monday through Friday",
+        unwrapped_description("0 0 * * 1-5/2")
+    );
+    assert_eq!(
+        "At 12:00 AM, every 3 months, January through October",
+        unwrapped_description("0 0 * 1-12/3 *")
+    );
+}
+
+#[test]
+fn test_verbosity() {
+    let verbose = Options {
+        verbosity: Verbosity::Verbose,
+        ..Options::options()
+    };
+    assert_eq!(
+        "Every minute, every hour, every day, every month, every year",
+        unwrapped_description_options("* * * * *", &verbose)
+    );
+    assert_eq!(
+        "At 9:00 AM, Monday through Friday, every month, every year",
+        unwrapped_description_options("0 9 * * MON-FRI", &verbose)
+    );
+    assert_eq!(
+        "Every minute, starting at 5 minutes past the hour",
+        unwrapped_description("5/1 * * * *")
+    );
+
+    let compact = Options {
+        verbosity: Verbosity::Compact,
+        ..Options::options()
+    };
+    assert_eq!(
+        "Mon–Fri 09:00",
+        unwrapped_description_options("0 9 * * MON-FRI", &compact)
+    );
+    assert_eq!(
+        "Mon, Wed, Fri 09:00, 09:30, 17:00, 17:30",
+        unwrapped_description_options("0,30 9,17 * * 1,3,5", &compact)
+    );
+    assert_eq!(
+        "09:00, Jan–Mar, Jul, 2025–2027",
+        unwrapped_description_options("0 9 * JAN-MAR,JUL * 2025-2027", &compact)
+    );
+    assert_eq!(
+        "09:00, on the second Monday of the month",
+        unwrapped_description_options("0 9 ? * MON#2", &compact)
+    );
+    assert_eq!(
+        "Every 5 minutes",
+        unwrapped_description_options("*/5 * * * *", &compact)
+    );
+    assert_eq!(
+        "00:00, on day 1 and 15 of the month or Mon",
+        unwrapped_description_options("0 0 1,15 * MON", &compact)
+    );
+}
+
+#[test]
+fn test_compact_style() {
+    let compact = Options {
+        verbosity: Verbosity::Compact,
+        abbreviated: true,
+        ..Options::options()
+    };
+    assert_eq!(
+        "Weekdays 9a–5p, every 15 min",
+        unwrapped_description_options("*/15 9-17 * * 1-5", &compact)
+    );
+    assert_eq!(
+        "Weekends 9a–5p, hourly",
+        unwrapped_description_options("0 9-17 * * SAT,SUN", &compact)
+    );
+    assert_eq!(
+        "9a–5p, every 2 h",
+        unwrapped_description_options("0 9-17/2 * * *", &compact)
+    );
+    assert_eq!(
+        "9:30a, day 1, 15",
+        unwrapped_description_options("30 9 1,15 * *", &compact)
+    );
+    assert_eq!(
+        "12a, day 1, 15 or Mon–Fri",
+        unwrapped_description_options("0 0 1,15 * MON-FRI", &compact)
+    );
+    assert_eq!("Every min", unwrapped_description_options("* * * * *", &compact));
+    assert_eq!("Every 10 s", unwrapped_description_options("*/10 * * * * *", &compact));
+
+    let twenty_four_hour = Options {
+        clock: Clock::TwentyFourHour,
+        ..compact
+    };
+    assert_eq!(
+        "Weekdays 09:00–17:00, every 15 min",
+        unwrapped_description_options("*/15 9-17 * * 1-5", &twenty_four_hour)
+    );
+}
+
+#[test]
+fn test_compact_length_budget() {
+    let budget = |max_length| Options {
+        verbosity: Verbosity::Compact,
+        max_length: Some(max_length),
+        ..Options::options()
+    };
+    // Fits as it is.
+    assert_eq!(
+        "09:30, on day 1 and 15 of the month",
+        unwrapped_description_options("30 9 1,15 * *", &budget(40))
+    );
+    // Abbreviated.
+    assert_eq!(
+        "9:30a, day 1, 15",
+        unwrapped_description_options("30 9 1,15 * *", &budget(30))
+    );
+    // Without the optional words.
+    assert_eq!(
+        "9:30a, 1, 15",
+        unwrapped_description_options("30 9 1,15 * *", &budget(15))
+    );
+    assert_eq!(
+        "9a, second Monday",
+        unwrapped_description_options("0 9 ? * MON#2", &budget(20))
+    );
+    assert_eq!(
+        "12a, 1, 15 or Mon",
+        unwrapped_description_options("0 0 1,15 * MON", &budget(20))
+    );
+    // Cut at a word boundary as a last resort.
+    assert_eq!(
+        "9:30a, 1…",
+        unwrapped_description_options("30 9 1,15 * *", &budget(10))
+    );
+    assert_eq!(
+        "Weekdays 9a–5p…",
+        unwrapped_description_options("*/15 9-17 * * 1-5", &budget(20))
+    );
+    assert_eq!(
+        "Mon, Wed, Fri 9a…",
+        unwrapped_description_options("0,30 9,17 * * 1,3,5", &budget(20))
+    );
+}
+
+#[test]
+fn test_ordinal_days() {
+    let ordinal = Options {
+        ordinal_days: true,
+        ..Options::options()
+    };
+    assert_eq!(
+        "At 12:00 AM, on the 1st and 15th of the month",
+        unwrapped_description_options("0 0 1,15 * *", &ordinal)
+    );
+    assert_eq!(
+        "At 12:00 AM, on the 2nd, 3rd, 11th, 12th, 13th, 21st, 22nd and 23rd of the month",
+        unwrapped_description_options("0 0 2,3,11,12,13,21,22,23 * *", &ordinal)
+    );
+    assert_eq!(
+        "At 9:00 AM, between the 1st and the 10th of the month",
+        unwrapped_description_options("0 9 1-10 * *", &ordinal)
+    );
+    assert_eq!(
+        "At 9:00 AM, every 10 days, starting on the 5th of the month",
+        unwrapped_description_options("0 9 5/10 * *", &ordinal)
+    );
+    assert_eq!(
+        "At 9:00 AM, on the weekday nearest the 15th of the month",
+        unwrapped_description_options("0 9 15W * ?", &ordinal)
+    );
+    assert_eq!(
+        "At 9:00 AM, on the 2nd Monday of the month",
+        unwrapped_description_options("0 9 ? * MON#2", &ordinal)
+    );
+    assert_eq!(
+        "At 9:00 AM, on the 1st Monday and the 3rd Friday of the month",
+        unwrapped_description_options("0 9 ? * MON#1,FRI#3", &ordinal)
+    );
+}
+
+#[test]
+fn test_time_formatting() {
+    let spoken = Options {
+        noon_and_midnight: true,
+        hour_only: true,
+        ..Options::options()
+    };
+    assert_eq!(
+        "At noon",
+        unwrapped_description_options("0 12 * * *", &spoken)
+    );
+    assert_eq!(
+        "At midnight",
+        unwrapped_description_options("0 0 * * *", &spoken)
+    );
+    assert_eq!(
+        "At 9 AM and 5 PM",
+        unwrapped_description_options("0 9,17 * * *", &spoken)
+    );
+    assert_eq!(
+        "At 9:30 AM",
+        unwrapped_description_options("30 9 * * *", &spoken)
+    );
+    assert_eq!(
+        "Every 5 minutes between 9 AM and 5:55 PM",
+        unwrapped_description_options("*/5 9-17 * * *", &spoken)
+    );
+    assert_eq!(
+        "Between midnight and 2 AM",
+        unwrapped_description_options("0 0-2 * * *", &spoken)
+    );
+    let locale = Options {
+        clock: Clock::Locale,
+        ..Options::options()
+    };
+    assert_eq!(
+        "At 9:00 AM and 5:00 PM",
+        unwrapped_description_options("0 9,17 * * *", &locale)
+    );
+    let pattern = Options {
+        time_pattern: Some("%-I.%M %p"),
+        ..Options::options()
+    };
+    assert_eq!(
+        "At 9.30 PM",
+        unwrapped_description_options("30 21 * * *", &pattern)
+    );
+    assert_eq!(
+        "At 1.00:05 AM and 1.00:06 AM",
+        unwrapped_description_options("5,6 0 1 * * *", &pattern)
+    );
+    let french = Options {
+        time_pattern: Some("%Hh%M"),
+        ..Options::options()
+    };
+    assert_eq!(
+        "Between 00h00 and 02h00",
+        unwrapped_description_options("0 0-2 * * *", &french)
+    );
+    let separator = String::from("h");
+    let built = format!("%H{}%M", separator);
+    let runtime = Options {
+        time_pattern: Some(&built),
+        ..Options::options()
+    };
+    assert_eq!(
+        "At 21h30",
+        unwrapped_description_options("30 21 * * *", &runtime)
+    );
+}
+
+#[test]
+fn test_plurals() {
+    assert_eq!("At 1 second past the minute", unwrapped_description("1 * * * * *"));
+    assert_eq!("At 1 and 2 seconds past the minute", unwrapped_description("1,2 * * * * *"));
+    assert_eq!("Every 5 seconds", unwrapped_description("*/5 * * * * *"));
+    assert_eq!("Every second", unwrapped_description("*/1 * * * * *"));
+    assert_eq!("At 9:00 AM, 1 day before the last day of the month", unwrapped_description("0 9 L-1 * ?"));
+    assert_eq!("At 9:00 AM, 2 days before the last day of the month", unwrapped_description("0 9 L-2 * ?"));
+}
+
+#[test]
+fn test_localized_names() {
+    let portuguese = Options {
+        name_locale: NameLocale::Locale("pt-BR"),
+        ..Options::options()
+    };
+    assert_eq!(
+        "At 9:00 AM, Monday through Friday",
+        unwrapped_description_options("0 9 * * SEG-SEX", &portuguese)
+    );
+    assert_eq!(
+        "At 9:00 AM, only on Monday, only in April",
+        unwrapped_description_options("0 9 * ABR MON", &portuguese)
+    );
+    let schedule = cron_descriptor::schedule::parse("0 9 * * SEG#2", &portuguese).unwrap();
+    assert!(schedule.matches_date(2025, 6, 9));
+    assert!(!schedule.matches_date(2025, 6, 2));
+    let header = String::from("es-MX,es;q=0.9");
+    let negotiated = cron_descriptor::locale::negotiate(&header);
+    let spanish = Options {
+        name_locale: NameLocale::Locale(&negotiated),
+        ..Options::options()
+    };
+    assert_eq!(
+        "At 9:00 AM, Monday through Friday",
+        unwrapped_description_options("0 9 * * LUN-VIE", &spanish)
+    );
+}
@@ -1,7 +1,7 @@
 use cron_descriptor;
 extern crate strfmt;
 
-use cron_descriptor::cronparser::Options;
+use cron_descriptor::cronparser::{Clock, NameLocale, Options, Verbosity};
 mod test_utils;
 use crate::test_utils::unwrapped_description_options;
 use crate::test_utils::unwrapped_description;
@@ -46,7 +46,7 @@ fn test_every45seconds() {
 #[test]
 fn test_minute_span() {
     assert_eq!(
-        "Cada minuto entre 11:00 AM y 11:10 AM",
+        "Cada minuto entre 11:00 y 11:10",
         unwrapped_description("0-10 11 * * *")
     );
     assert_eq!(
@@ -57,11 +57,11 @@ fn test_minute_span() {
         )
     );
     assert_eq!(
-        "Cada minuto, en 1:00 AM",
+        "Cada minuto, en 01:00",
         unwrapped_description("* 1 * * *")
     );
     assert_eq!(
-        "Cada minuto, en 12:00 AM",
+        "Cada minuto, en 00:00",
         unwrapped_description("* 0 * * *")
     );
 }
@@ -117,7 +117,7 @@ fn test_every_xminutes() {
 #[test]
 fn test_daily_at_time() {
     assert_eq!(
-        "En 11:30 AM",
+        "En 11:30",
         unwrapped_description("30 11 * * *")
     );
     assert_eq!(
@@ -128,7 +128,7 @@ fn test_daily_at_time() {
         )
     );
     assert_eq!(
-        "En 11:00 AM",
+        "En 11:00",
         unwrapped_description("0 11 * * *")
     );
 }
@@ -136,7 +136,7 @@ fn test_daily_at_time() {
 #[test]
 fn test_time_of_day_certain_days_of_week() {
     assert_eq!(
-        "En 11:00 PM, lunes hasta viernes",
+        "En 23:00, lunes hasta viernes",
         unwrapped_description("0 23 ? * MON-FRI")
     );
     assert_eq!(
@@ -147,7 +147,7 @@ fn test_time_of_day_certain_days_of_week() {
         )
     );
     assert_eq!(
-        "En 11:30 AM, lunes hasta viernes",
+        "En 11:30, lunes hasta viernes",
         unwrapped_description("30 11 * * 1-5")
     );
 }
@@ -171,7 +171,7 @@ fn test_two_months_only() {
 #[test]
 fn test_two_times_each_afternoon() {
     assert_eq!(
-        "En 2:30 PM y 4:30 PM",
+        "En 14:30 y 16:30",
         unwrapped_description("30 14,16 * * *")
     );
     assert_eq!(
@@ -186,7 +186,7 @@ fn test_two_times_each_afternoon() {
 #[test]
 fn test_three_times_daily() {
     assert_eq!(
-        "En 6:30 AM, 2:30 PM y 4:30 PM",
+        "En 06:30, 14:30 y 16:30",
         unwrapped_description("30 6,14,16 * * *")
     );
     assert_eq!(
@@ -201,19 +201,19 @@ fn test_three_times_daily() {
 #[test]
 fn test_once_aweek() {
     assert_eq!(
-        "En 9:46 AM, sólo en domingo",
+        "En 09:46, sólo en domingo",
         unwrapped_description("46 9 * * 0")
     );
     assert_eq!(
-        "En 9:46 AM, sólo en domingo",
+        "En 09:46, sólo en domingo",
         unwrapped_description("46 9 * * 7")
     );
     assert_eq!(
-        "En 9:46 AM, sólo en lunes",
+        "En 09:46, sólo en lunes",
         unwrapped_description("46 9 * * 1")
     );
     assert_eq!(
-        "En 9:46 AM, sólo en sábado",
+        "En 09:46, sólo en sábado",
         unwrapped_description("46 9 * * 6")
     );
 }
@@ -225,21 +225,21 @@ fn test_once_aweek_non_zero_based() {
         ..Options::options()
     };
     assert_eq!(
-        "En 9:46 AM, sólo en domingo",
+        "En 09:46, sólo en domingo",
         unwrapped_description_options(
             "46 9 * * 1",
             &options
         )
     );
     assert_eq!(
-        "En 9:46 AM, sólo en lunes",
+        "En 09:46, sólo en lunes",
         unwrapped_description_options(
             "46 9 * * 2",
             &options
         )
     );
     assert_eq!(
-        "En 9:46 AM, sólo en sábado",
+        "En 09:46, sólo en sábado",
         unwrapped_description_options(
             "46 9 * * 7",
             &options
@@ -250,15 +250,15 @@ fn test_once_aweek_non_zero_based() {
 #[test]
 fn test_twice_aweek() {
     assert_eq!(
-        "En 9:46 AM, sólo en lunes y martes",
+        "En 09:46, sólo en lunes y martes",
         unwrapped_description("46 9 * * 1,2")
     );
     assert_eq!(
-        "En 9:46 AM, sólo en domingo y sábado",
+        "En 09:46, sólo en domingo y sábado",
         unwrapped_description("46 9 * * 0,6")
     );
     assert_eq!(
-        "En 9:46 AM, sólo en sábado y domingo",
+        "En 09:46, sólo en sábado y domingo",
         unwrapped_description("46 9 * * 6,7")
     );
 }
@@ -270,14 +270,14 @@ fn test_twice_aweek_non_zero_based() {
         ..Options::options()
     };
     assert_eq!(
-        "En 9:46 AM, sólo en domingo y lunes",
+        "En 09:46, sólo en domingo y lunes",
         unwrapped_description_options(
             "46 9 * * 1,2",
             &options
         )
     );
     assert_eq!(
-        "En 9:46 AM, sólo en viernes y sábado",
+        "En 09:46, sólo en viernes y sábado",
         unwrapped_description_options(
             "46 9 * * 6,7",
             &options
@@ -288,7 +288,7 @@ fn test_twice_aweek_non_zero_based() {
 #[test]
 fn test_day_of_month() {
     assert_eq!(
-        "En 12:23 PM, en el 15 día del mes",
+        "En 12:23, en el 15 día del mes",
         unwrapped_description("23 12 15 * *")
     );
     assert_eq!(
@@ -303,7 +303,7 @@ fn test_day_of_month() {
 #[test]
 fn test_month_name() {
     assert_eq!(
-        "En 12:23 PM, sólo en enero",
+        "En 12:23, sólo en enero",
         unwrapped_description("23 12 * JAN *")
     );
 }
@@ -311,7 +311,7 @@ fn test_month_name() {
 #[test]
 fn test_day_of_month_with_question_mark() {
     assert_eq!(
-        "En 12:23 PM, sólo en enero",
+        "En 12:23, sólo en enero",
         unwrapped_description("23 12 ? JAN *")
     );
 }
@@ -319,7 +319,7 @@ fn test_day_of_month_with_question_mark() {
 #[test]
 fn test_month_name_range2() {
     assert_eq!(
-        "En 12:23 PM, enero hasta febrero",
+        "En 12:23, enero hasta febrero",
         unwrapped_description("23 12 * JAN-FEB *")
     );
 }
@@ -327,7 +327,7 @@ fn test_month_name_range2() {
 #[test]
 fn test_month_name_range3() {
     assert_eq!(
-        "En 12:23 PM, enero hasta marzo",
+        "En 12:23, enero hasta marzo",
         unwrapped_description("23 12 * JAN-MAR *")
     );
 }
@@ -335,7 +335,7 @@ fn test_month_name_range3() {
 #[test]
 fn test_month_name_ranges() {
     assert_eq!(
-        "En 3:00 AM, sólo en enero hasta marzo y mayo hasta junio",
+        "En 03:00, sólo en enero hasta marzo y mayo hasta junio",
         unwrapped_description("0 0 3 * 1-3,5-6 *")
     );
 }
@@ -343,7 +343,7 @@ fn test_month_name_ranges() {
 #[test]
 fn test_day_of_week_name() {
     assert_eq!(
-        "En 12:23 PM, sólo en domingo",
+        "En 12:23, sólo en domingo",
         unwrapped_description("23 12 * * SUN")
     );
 }
@@ -351,15 +351,15 @@ fn test_day_of_week_name() {
 #[test]
 fn test_day_of_week_range() {
     assert_eq!(
-        "Cada 5 minutos, en 3:00 PM, lunes hasta viernes",
+        "Cada 5 minutos entre 15:00 y 15:55, lunes hasta viernes",
         unwrapped_description("*/5 15 * * MON-FRI")
     );
     assert_eq!(
-        "Cada 5 minutos, en 3:00 PM, domingo hasta sábado",
+        "Cada 5 minutos entre 15:00 y 15:55, domingo hasta sábado",
         unwrapped_description("*/5 15 * * 0-6")
     );
     assert_eq!(
-        "Cada 5 minutos, en 3:00 PM, sábado hasta domingo",
+        "Cada 5 minutos entre 15:00 y 15:55, sábado hasta domingo",
         unwrapped_description("*/5 15 * * 6-7")
     );
 }
@@ -367,7 +367,7 @@ fn test_day_of_week_range() {
 #[test]
 fn test_day_of_week_ranges() {
     assert_eq!(
-        "En 3:00 AM, sólo en domingo, martes hasta jueves y sábado",
+        "En 03:00, sólo en domingo, martes hasta jueves y sábado",
         unwrapped_description("0 0 3 * * 0,2-4,6")
     );
 }
@@ -375,13 +375,17 @@ fn test_day_of_week_ranges() {
 #[test]
 fn test_day_of_week_once_in_month() {
     assert_eq!(
-        "Cada minuto, en el terzero lunes del mes",
+        "Cada minuto, en el tercer lunes del mes",
         unwrapped_description("* * * * MON#3")
     );
     assert_eq!(
-        "Cada minuto, en el terzero domingo del mes",
+        "Cada minuto, en el tercer domingo del mes",
         unwrapped_description("* * * * 0#3")
     );
+    assert_eq!(
+        "Cada minuto, en el primer sábado y el segundo domingo del mes",
+        unwrapped_description("* * * * SAT#1,SUN#2")
+    );
 }
 
 #[test]
@@ -407,7 +411,7 @@ fn test_last_day_of_the_month() {
 #[test]
 fn test_time_of_day_with_seconds() {
     assert_eq!(
-        "En 2:02:30 PM",
+        "En 14:02:30",
         unwrapped_description("30 02 14 * * *")
     );
 }
@@ -422,7 +426,7 @@ fn test_second_intervals() {
 
 #[test]
 fn test_second_minutes_hours_intervals() {
-    assert_eq!("5 segundos 10 después el minuto, desde 30 hasta el 35 minuto después de la hora, entre 10:00 AM y 12:00 PM",
+    assert_eq!("5 segundos 10 después el minuto, desde 30 hasta el 35 minuto después de la hora, entre 10:00 y 12:00",
                    unwrapped_description("5-10 30-35 10-12 * * *"));
 }
 
@@ -437,7 +441,7 @@ fn test_every5minutes_at30seconds() {
 #[test]
 fn test_minutes_past_the_hour_range() {
     assert_eq!(
-        "En 30 minutos pasada la hora, entre 10:00 AM y 1:00 PM, sólo en miércoles y viernes",
+        "En 30 minutos pasada la hora, entre 10:00 y 13:00, sólo en miércoles y viernes",
         unwrapped_description("0 30 10-13 ? * WED,FRI")
     );
 }
@@ -452,14 +456,14 @@ fn test_seconds_past_the_minute_interval() {
 
 #[test]
 fn test_between_with_interval() {
-    assert_eq!("Cada 3 minutos, desde 02 hasta el 59 minuto después de la hora, en 1:00 AM, 9:00 AM y 10:00 PM, entre el 11 y el 26 del mes, enero hasta junio",
+    assert_eq!("Cada 3 minutos, desde 02 hasta el 59 minuto después de la hora, en 01:00, 09:00 y 22:00, entre el 11 y el 26 del mes, enero hasta junio",
                    unwrapped_description("2-59/3 1,9,22 11-26 1-6 ?"));
 }
 
 #[test]
 fn test_recurring_first_of_month() {
     assert_eq!(
-        "En 6:00 AM",
+        "En 06:00",
         unwrapped_description("0 0 6 1/1 * ?")
     );
 }
@@ -491,7 +495,7 @@ fn test_every_past_the_hour() {
 #[test]
 fn test_every_xminute_past_the_hour_with_interval() {
     assert_eq!(
-        "Cada 2 minutos, desde 00 hasta el 30 minuto después de la hora, en 5:00 PM, lunes hasta viernes",
+        "Cada 2 minutos entre 17:00 y 17:30, lunes hasta viernes",
         unwrapped_description("0 0-30/2 17 ? * MON-FRI")
     );
 }
@@ -526,7 +530,7 @@ fn test_two_years_only() {
 #[test]
 fn test_year_range2() {
     assert_eq!(
-        "En 12:23 PM, enero hasta febrero, 2013 hasta 2014",
+        "En 12:23, enero hasta febrero, 2013 hasta 2014",
         unwrapped_description("23 12 * JAN-FEB * 2013-2014")
     );
 }
@@ -534,7 +538,7 @@ fn test_year_range2() {
 #[test]
 fn test_year_range3() {
     assert_eq!(
-        "En 12:23 PM, enero hasta marzo, 2013 hasta 2015",
+        "En 12:23, enero hasta marzo, 2013 hasta 2015",
         unwrapped_description("23 12 * JAN-MAR * 2013-2015")
     );
 }
@@ -546,7 +550,7 @@ fn test_issue26() {
         unwrapped_description("5,10 * * * *")
     );
     assert_eq!(
-        "En 05 y 10 minutos pasada la hora, en 12:00 AM",
+        "En 00:05 y 00:10",
         unwrapped_description("5,10 0 * * *")
     );
     assert_eq!(
@@ -554,7 +558,7 @@ fn test_issue26() {
         unwrapped_description("5,10 * 2 * *")
     );
     assert_eq!(
-        "Cada 10 minutos, en el 2 día del mes",
+        "Cada 10 minutos, comenzando en 5 minutos pasada la hora, en el 2 día del mes",
         unwrapped_description("5/10 * 2 * *")
     );
 
@@ -563,7 +567,7 @@ fn test_issue26() {
         unwrapped_description("5,6 0 * * * *")
     );
     assert_eq!(
-        "5 y 6 segundos después el minuto, en 1:00 AM",
+        "En 01:00:05 y 01:00:06",
         unwrapped_description("5,6 0 1 * * *")
     );
     assert_eq!(
@@ -571,3 +575,283 @@ fn test_issue26() {
         unwrapped_description("5,6 0 * 2 * *")
     );
 }
+
+#[test]
+fn test_month_names() {
+    test_utils::assert_month_names_like_numbers();
+}
+
+#[test]
+fn test_day_of_week_names() {
+    test_utils::assert_day_of_week_names_like_numbers();
+}
+
+#[test]
+fn test_names_in_any_case() {
+    assert_eq!("En 09:00, sólo en diciembre", unwrapped_description("0 9 * DEC *"));
+    assert_eq!(
+        "En 09:00, sólo en enero y marzo hasta mayo",
+        unwrapped_description("0 9 * jan,MAR-May *")
+    );
+    assert_eq!(
+        "En 09:00, sólo en sábado y domingo",
+        unwrapped_description("0 9 * * sat,SUN")
+    );
+    assert_eq!(
+        "En 09:00, lunes hasta viernes",
+        unwrapped_description("0 9 * * Monday-Friday")
+    );
+    assert_eq!(
+        "En 09:00, en el último viernes del mes",
+        unwrapped_description("0 9 ? * FRIL")
+    );
+}
+
+#[test]
+fn test_offset_steps() {
+    assert_eq!(
+        "Cada 15 minutos, comenzando en 5 minutos pasada la hora",
+        unwrapped_description("5/15 * * * *")
+    );
+    assert_eq!(
+        "Cada 3 horas, comenzando en 05:00",
+        unwrapped_description("0 5/3 * * *")
+    );
+    assert_eq!(
+        "En 09:00, cada 10 días, comenzando en el 5 día del mes",
+        unwrapped_description("0 9 5/10 * *")
+    );
+    assert_eq!(
+        "En 09:00, cada 2 meses, comenzando en marzo",
+        unwrapped_description("0 9 * 3/2 *")
+    );
+    assert_eq!(
+        "En 09:00, cada 2 días de la semana, comenzando el martes",
+        unwrapped_description("0 9 ? * 2/2")
+    );
+    assert_eq!(
+        "En 09:00, cada 2 años, comenzando en 2025",
+        unwrapped_description("0 0 9 * * ? 2025/2")
+    );
+}
+
+#[test]
+fn test_last_day_offsets_and_nearest_weekday() {
+    assert_eq!(
+        "En 09:00, 3 días antes del último día del mes",
+        unwrapped_description("0 9 L-3 * ?")
+    );
+    assert_eq!(
+        "En 09:00, 1 día antes del último día del mes",
+        unwrapped_description("0 9 L-1 * ?")
+    );
+    assert_eq!(
+        "En 09:00, en el día de la semana mas cercano a 15 del mes",
+        unwrapped_description("0 9 15W * ?")
+    );
+}
+
+#[test]
+fn test_list_of_occurrences_in_the_month() {
+    assert_eq!(
+        "En 09:00, en el último lunes y el último viernes del mes",
+        unwrapped_description("0 9 ? * 1L,5L")
+    );
+}
+
+#[test]
+fn test_wrap_around_ranges() {
+    assert_eq!(
+        "Entre 22:00 y 02:00 durante la noche",
+        unwrapped_description("0 22-2 * * *")
+    );
+    assert_eq!(
+        "Entre 00:00 y 02:00 y entre 22:00 y 23:00, sólo en viernes",
+        unwrapped_description("0 22-2 * * FRI")
+    );
+}
+
+#[test]
+fn test_day_of_month_or_day_of_week() {
+    assert_eq!(
+        "En 00:00, en el 1 y 15 día del mes, o el lunes",
+        unwrapped_description("0 0 1,15 * MON")
+    );
+}
+
+#[test]
+fn test_listed_times() {
+    assert_eq!(
+        "En 09:00, 09:30, 17:00 y 17:30",
+        unwrapped_description("0,30 9,17 * * *")
+    );
+}
+
+#[test]
+fn test_steps_within_windows() {
+    assert_eq!(
+        "Cada 5 minutos entre 09:00 y 17:55, lunes hasta viernes",
+        unwrapped_description("*/5 9-17 * * MON-FRI")
+    );
+    assert_eq!(
+        "Cada minuto entre 09:00 y 09:30 y entre 17:00 y 17:30",
+        unwrapped_description("0-30 9,17 * * *")
+    );
+}
+
+#[test]
+fn test_verbosity() {
+    let verbose = Options {
+        verbosity: Verbosity::Verbose,
+        ..Options::options()
+    };
+    assert_eq!(
+        "En 09:00, cada día, cada mes, cada año",
+        unwrapped_description_options("0 9 * * *", &verbose)
+    );
+    let compact = Options {
+        verbosity: Verbosity::Compact,
+        ..Options::options()
+    };
+    assert_eq!(
+        "Lun–vie 09:00",
+        unwrapped_description_options("0 9 * * MON-FRI", &compact)
+    );
+    let abbreviated = Options {
+        abbreviated: true,
+        ..compact
+    };
+    assert_eq!(
+        "Laborables 09:00–17:00, cada 15 min",
+        unwrapped_description_options("*/15 9-17 * * 1-5", &abbreviated)
+    );
+    assert_eq!(
+        "09:00, ene–mar, jul",
+        unwrapped_description_options("0 9 * JAN-MAR,JUL *", &abbreviated)
+    );
+    let twelve_hour = Options {
+        clock: Clock::TwelveHour,
+        ..abbreviated
+    };
+    assert_eq!(
+        "Laborables 9\u{a0}a.\u{a0}m.–5\u{a0}p.\u{a0}m., cada 15 min",
+        unwrapped_description_options("*/15 9-17 * * 1-5", &twelve_hour)
+    );
+    assert_eq!(
+        "Lun, mié, vie 9\u{a0}a.\u{a0}m.",
+        unwrapped_description_options("0 9 * * 1,3,5", &twelve_hour)
+    );
+    let budget = Options {
+        max_length: Some(20),
+        ..abbreviated
+    };
+    assert_eq!(
+        "Laborables…",
+        unwrapped_description_options("*/15 9-17 * * 1-5", &budget)
+    );
+    assert_eq!(
+        "09:00, segundo lunes",
+        unwrapped_description_options("0 9 ? * MON#2", &budget)
+    );
+    assert_eq!(
+        "00:00, 1, 15 o lun",
+        unwrapped_description_options("0 0 1,15 * MON", &budget)
+    );
+}
+
+#[test]
+fn test_ordinal_days() {
+    let ordinal = Options {
+        ordinal_days: true,
+        ..Options::options()
+    };
+    assert_eq!(
+        "En 00:00, el día 1.º y 15.º del mes",
+        unwrapped_description_options("0 0 1,15 * *", &ordinal)
+    );
+    assert_eq!(
+        "En 09:00, entre el día 1.º y el 10.º del mes",
+        unwrapped_description_options("0 9 1-10 * *", &ordinal)
+    );
+    assert_eq!(
+        "En 09:00, cada 10 días, comenzando el día 5.º del mes",
+        unwrapped_description_options("0 9 5/10 * *", &ordinal)
+    );
+    assert_eq!(
+        "En 09:00, en el 2.º lunes del mes",
+        unwrapped_description_options("0 9 ? * MON#2", &ordinal)
+    );
+    assert_eq!(
+        "En 09:00, en el 1.er lunes y el 3.er viernes del mes",
+        unwrapped_description_options("0 9 ? * MON#1,FRI#3", &ordinal)
+    );
+}
+
+#[test]
+fn test_time_formatting() {
+    let locale = Options {
+        clock: Clock::Locale,
+        ..Options::options()
+    };
+    assert_eq!(
+        "En 09:00 y 17:00",
+        unwrapped_description_options("0 9,17 * * *", &locale)
+    );
+    assert_eq!(
+        "Entre 00:00 y 02:00",
+        unwrapped_description_options("0 0-2 * * *", &locale)
+    );
+    let twelve_hour = Options {
+        clock: Clock::TwelveHour,
+        ..Options::options()
+    };
+    assert_eq!(
+        "En 9:00 AM y 5:00 PM",
+        unwrapped_description_options("0 9,17 * * *", &twelve_hour)
+    );
+    let spoken = Options {
+        noon_and_midnight: true,
+        hour_only: true,
+        ..Options::options()
+    };
+    assert_eq!(
+        "En medianoche",
+        unwrapped_description_options("0 0 * * *", &spoken)
+    );
+    assert_eq!(
+        "En 09:00 y 17:00",
+        unwrapped_description_options("0 9,17 * * *", &spoken)
+    );
+}
+
+#[test]
+fn test_plurals() {
+    assert_eq!("1 segundo después el minuto", unwrapped_description("1 * * * * *"));
+    assert_eq!("1 y 2 segundos después el minuto", unwrapped_description("1,2 * * * * *"));
+    assert_eq!("En 09:00, 1 día antes del último día del mes", unwrapped_description("0 9 L-1 * ?"));
+}
+
+#[test]
+fn test_localized_names() {
+    let spanish = Options {
+        name_locale: NameLocale::Current,
+        ..Options::options()
+    };
+    assert_eq!(
+        "En 09:00, lunes hasta viernes",
+        unwrapped_description_options("0 9 * * LUN-VIE", &spanish)
+    );
+    assert_eq!(
+        "En 09:00, sólo en miércoles, sólo en enero y febrero",
+        unwrapped_description_options("0 9 * ENE,FEB mie", &spanish)
+    );
+    assert_eq!(
+        "En 09:00, en el último viernes del mes, sólo en septiembre",
+        unwrapped_description_options("0 9 * Septiembre VIEL", &spanish)
+    );
+    // English names keep their meaning.
+    assert_eq!(
+        "En 09:00, lunes hasta viernes, sólo en marzo",
+        unwrapped_description_options("0 9 * MAR MON-FRI", &spanish)
+    );
+}
@@ -0,0 +1,91 @@
+use cron_descriptor::cronparser::{Clock, Options, Verbosity};
+mod test_utils;
+use crate::test_utils::{unwrapped_description, unwrapped_description_options};
+
+#[ctor::ctor]
+fn init() {
+    rust_i18n::set_locale("fr");
+}
+
+#[test]
+fn test_every_minute() {
+    assert_eq!("Toutes les minutes", unwrapped_description("* * * * *"));
+    assert_eq!("Toutes les secondes", unwrapped_description("*/1 * * * * *"));
+    assert_eq!("Toutes les minutes entre 11:00 et 11:10", unwrapped_description("0-10 11 * * *"));
+    assert_eq!("Toutes les minutes, à 01:00", unwrapped_description("* 1 * * *"));
+}
+
+#[test]
+fn test_twenty_four_hour_times() {
+    assert_eq!("À 11:30", unwrapped_description("30 11 * * *"));
+    assert_eq!("À 23:00, du lundi au vendredi", unwrapped_description("0 23 ? * MON-FRI"));
+    assert_eq!("À 14:30 et 16:30", unwrapped_description("30 14,16 * * *"));
+    assert_eq!("Entre 00:00 et 02:00", unwrapped_description("0 0-2 * * *"));
+    assert_eq!("Toutes les 5 minutes entre 09:00 et 17:55", unwrapped_description("*/5 9-17 * * *"));
+}
+
+#[test]
+fn test_plurals() {
+    assert_eq!("À 1 seconde après la minute", unwrapped_description("1 * * * * *"));
+    assert_eq!("À 1 et 2 secondes après la minute", unwrapped_description("1,2 * * * * *"));
+    assert_eq!("Toutes les 5 minutes", unwrapped_description("*/5 * * * *"));
+    assert_eq!("Toutes les 2 heures", unwrapped_description("0 */2 * * *"));
+    assert_eq!("À 00:00, tous les 2 jours", unwrapped_description("0 0 */2 * *"));
+    assert_eq!("À 09:00, 1 jour avant le dernier jour du mois", unwrapped_description("0 9 L-1 * ?"));
+    assert_eq!("À 09:00, 2 jours avant le dernier jour du mois", unwrapped_description("0 9 L-2 * ?"));
+}
+
+#[test]
+fn test_days_of_week() {
+    assert_eq!("À 09:00, du lundi au vendredi", unwrapped_description("0 9 * * MON-FRI"));
+    assert_eq!("À 09:00, uniquement le mardi, le mercredi et le vendredi", unwrapped_description("0 9 * * 2,3,5"));
+    assert_eq!("À 09:00, uniquement le dimanche", unwrapped_description("0 9 * * 0"));
+    assert_eq!("À 09:00, tous les 2 jours de la semaine, en commençant le mardi", unwrapped_description("0 9 * * 2/2"));
+    assert_eq!("À 09:00, le 1 du mois, ou le mardi", unwrapped_description("0 9 1 * TUE"));
+}
+
+#[test]
+fn test_nth_and_last_days() {
+    assert_eq!("À 09:00, le premier lundi du mois", unwrapped_description("0 9 ? * MON#1"));
+    assert_eq!("À 09:00, le premier dimanche du mois", unwrapped_description("0 9 ? * SUN#1"));
+    assert_eq!("À 09:00, le troisième vendredi et le premier lundi du mois", unwrapped_description("0 9 ? * FRI#3,MON#1"));
+    assert_eq!("À 09:00, le dernier vendredi du mois", unwrapped_description("0 9 ? * 5L"));
+    assert_eq!("À 09:00, le dernier jour du mois", unwrapped_description("0 9 L * ?"));
+    assert_eq!("À 09:00, le dernier jour ouvré du mois", unwrapped_description("0 9 LW * ?"));
+    assert_eq!("À 09:00, le jour ouvré le plus proche du 15 du mois", unwrapped_description("0 9 15W * ?"));
+}
+
+#[test]
+fn test_months_and_years() {
+    assert_eq!("À 09:00, de janvier à mars", unwrapped_description("0 9 * JAN-MAR *"));
+    assert_eq!("À 09:00, uniquement en janvier, en mars et en juillet", unwrapped_description("0 9 * 1,3,7 *"));
+    assert_eq!("À 09:00, tous les 2 mois, en commençant en mars", unwrapped_description("0 9 * 3/2 *"));
+    assert_eq!("À 09:00, de 2025 à 2027", unwrapped_description("0 9 * * * 2025-2027"));
+}
+
+#[test]
+fn test_ordinal_days() {
+    let ordinal = Options { ordinal_days: true, ..Options::options() };
+    assert_eq!("À 00:00, le 1er et 15e du mois", unwrapped_description_options("0 0 1,15 * *", &ordinal));
+    assert_eq!("À 09:00, du 1er au 10e du mois", unwrapped_description_options("0 9 1-10 * *", &ordinal));
+    assert_eq!("À 09:00, le 1er lundi et le 3e vendredi du mois", unwrapped_description_options("0 9 ? * MON#1,FRI#3", &ordinal));
+}
+
+#[test]
+fn test_verbosity() {
+    let verbose = Options { verbosity: Verbosity::Verbose, ..Options::options() };
+    let compact = Options { verbosity: Verbosity::Compact, ..Options::options() };
+    let abbreviated = Options { abbreviated: true, ..compact };
+    assert_eq!("À 09:00, du lundi au vendredi, tous les mois, tous les ans", unwrapped_description_options("0 9 * * MON-FRI", &verbose));
+    assert_eq!("Lun.–ven. 09:00", unwrapped_description_options("0 9 * * MON-FRI", &compact));
+    assert_eq!("En semaine 09:00–17:00, toutes les 15 min", unwrapped_description_options("*/15 9-17 * * 1-5", &abbreviated));
+}
+
+#[test]
+fn test_time_formatting() {
+    let twelve_hour = Options { clock: Clock::TwelveHour, ..Options::options() };
+    let spoken = Options { noon_and_midnight: true, ..Options::options() };
+    assert_eq!("À 9:30 PM", unwrapped_description_options("30 21 * * *", &twelve_hour));
+    assert_eq!("À midi", unwrapped_description_options("0 12 * * *", &spoken));
+    assert_eq!("Entre minuit et 02:00", unwrapped_description_options("0 0-2 * * *", &spoken));
+}
@@ -0,0 +1,36 @@
+use cron_descriptor::cronparser::Options;
+mod test_utils;
+use crate::test_utils::unwrapped_description_options;
+
+#[ctor::ctor]
+fn init() {
+    rust_i18n::set_locale("he");
+}
+
+fn description(cron_expr: &str) -> String {
+    unwrapped_description_options(cron_expr, &Options::twenty_four_hour())
+}
+
+#[test]
+fn test_dual_plurals() {
+    assert_eq!("כל \u{2066}2\u{2069} דקות", description("*/2 * * * *"));
+    assert_eq!("כל שעתיים", description("0 */2 * * *"));
+    assert_eq!("בשעה \u{2066}09:00\u{2069}, כל יומיים", description("0 9 */2 * *"));
+    assert_eq!("בשעה \u{2066}09:00\u{2069}, יום אחד לפני היום האחרון בחודש", description("0 9 L-1 * ?"));
+    assert_eq!(
+        "בשעה \u{2066}09:00\u{2069}, \u{2066}5\u{2069} ימים לפני היום האחרון בחודש",
+        description("0 9 L-5 * ?")
+    );
+}
+
+#[test]
+fn test_days_and_months() {
+    assert_eq!("בשעה \u{2066}09:00\u{2069}, מיום שני עד יום שישי", description("0 9 * * MON-FRI"));
+    assert_eq!(
+        "בשעה \u{2066}09:00\u{2069}, רק ביום שלישי, יום רביעי ויום שישי",
+        description("0 9 * * 2,3,5")
+    );
+    assert_eq!("בשעה \u{2066}09:00\u{2069}, ביום רביעי השני בחודש", description("0 9 ? * WED#2"));
+    assert_eq!("בשעה \u{2066}09:00\u{2069}, כל חודשיים, החל ממרץ", description("0 9 * 3/2 *"));
+    assert_eq!("בשעה \u{2066}09:00\u{2069}, ביום \u{2066}1\u{2069} בחודש, או ביום שני", description("0 9 1 * MON"));
+}
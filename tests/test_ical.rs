@@ -0,0 +1,377 @@
+use cron_descriptor::cronparser::Options;
+use cron_descriptor::ical::{from_rrule, to_ics, to_rrules, ConversionError, VEventOptions};
+use cron_descriptor::schedule::{self, DateTime};
+
+#[ctor::ctor]
+fn init() {
+    rust_i18n::set_locale("en");
+}
+
+fn rrules(cron_expr: &str) -> Vec<String> {
+    to_rrules(cron_expr, &Options::options()).unwrap()
+}
+
+fn imported(rule: &str) -> Vec<String> {
+    from_rrule(rule, &Options::options()).unwrap().expressions
+}
+
+#[test]
+fn test_weekly_rrule() {
+    assert_eq!(
+        vec!["FREQ=WEEKLY;BYDAY=MO,WE;BYHOUR=9;BYMINUTE=0"],
+        rrules("0 9 * * MON,WED")
+    );
+    assert_eq!(
+        vec!["FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR;BYHOUR=9;BYMINUTE=30"],
+        rrules("30 9 * * 1-5")
+    );
+}
+
+#[test]
+fn test_time_only_rrule() {
+    assert_eq!(vec!["FREQ=SECONDLY"], rrules("* * * * * *"));
+    assert_eq!(vec!["FREQ=MINUTELY"], rrules("* * * * *"));
+    assert_eq!(
+        vec!["FREQ=HOURLY;BYMINUTE=0,15,30,45"],
+        rrules("*/15 * * * *")
+    );
+    assert_eq!(vec!["FREQ=MINUTELY;BYHOUR=1"], rrules("* 1 * * *"));
+    assert_eq!(
+        vec!["FREQ=DAILY;BYHOUR=11;BYMINUTE=30;BYSECOND=15"],
+        rrules("15 30 11 * * *")
+    );
+}
+
+#[test]
+fn test_monthly_rrule() {
+    assert_eq!(
+        vec!["FREQ=MONTHLY;BYMONTHDAY=1,15;BYHOUR=0;BYMINUTE=0"],
+        rrules("0 0 1,15 * *")
+    );
+    assert_eq!(
+        vec!["FREQ=MONTHLY;BYMONTHDAY=-1;BYHOUR=0;BYMINUTE=0"],
+        rrules("0 0 0 L * ?")
+    );
+    assert_eq!(
+        vec!["FREQ=DAILY;BYMONTH=1,7;BYHOUR=12;BYMINUTE=0"],
+        rrules("0 12 * JAN,JUL *")
+    );
+}
+
+#[test]
+fn test_set_position_rrule() {
+    assert_eq!(
+        vec!["FREQ=MONTHLY;BYDAY=SA;BYHOUR=9;BYMINUTE=30;BYSETPOS=2"],
+        rrules("0 30 9 ? * 6#2")
+    );
+    assert_eq!(
+        vec!["FREQ=MONTHLY;BYDAY=FR;BYHOUR=9,17;BYMINUTE=0;BYSETPOS=-2,-1"],
+        rrules("0 0 9,17 ? * 5L")
+    );
+    assert_eq!(
+        vec!["FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYHOUR=0;BYMINUTE=0;BYSETPOS=-1"],
+        rrules("0 0 0 LW * ?")
+    );
+    assert_eq!(
+        vec![
+            "FREQ=MONTHLY;BYDAY=MO;BYHOUR=10;BYMINUTE=0;BYSETPOS=1",
+            "FREQ=MONTHLY;BYDAY=FR;BYHOUR=10;BYMINUTE=0;BYSETPOS=3"
+        ],
+        rrules("0 0 10 ? * 1#1,5#3")
+    );
+}
+
+#[test]
+fn test_day_of_month_or_day_of_week_rrule() {
+    assert_eq!(
+        vec![
+            "FREQ=MONTHLY;BYMONTHDAY=1,15;BYHOUR=0;BYMINUTE=0",
+            "FREQ=WEEKLY;BYDAY=MO;BYHOUR=0;BYMINUTE=0"
+        ],
+        rrules("0 0 1,15 * MON")
+    );
+}
+
+#[test]
+fn test_day_of_month_and_day_of_week_rrule() {
+    let both = Options {
+        match_any_day: false,
+        ..Options::options()
+    };
+    assert_eq!(
+        Ok(vec![
+            "FREQ=MONTHLY;BYMONTHDAY=13;BYDAY=FR;BYHOUR=9;BYMINUTE=0".to_string()
+        ]),
+        to_rrules("0 9 13 * FRI", &both)
+    );
+    assert!(matches!(
+        to_rrules("0 9 L * FRI#1", &both),
+        Err(ConversionError::Unsupported(_))
+    ));
+
+    let from = DateTime::new(2026, 1, 1, 0, 0, 0);
+    let next = |options: &Options| {
+        schedule::parse("0 9 13 * FRI", options)
+            .unwrap()
+            .next_occurrence(&from)
+    };
+    assert_eq!(Some(DateTime::new(2026, 1, 2, 9, 0, 0)), next(&Options::options()));
+    assert_eq!(Some(DateTime::new(2026, 2, 13, 9, 0, 0)), next(&both));
+}
+
+#[test]
+fn test_year_range_rrule() {
+    assert_eq!(
+        vec!["FREQ=DAILY;BYHOUR=12;BYMINUTE=0;UNTIL=20281231T235959"],
+        rrules("0 0 12 * * ? 2026-2028")
+    );
+}
+
+#[test]
+fn test_unsupported_rrule() {
+    assert_eq!(
+        Err(ConversionError::Unsupported(
+            "the weekday nearest day 15 (W) has no RRULE equivalent".to_string()
+        )),
+        to_rrules("0 0 0 15W * ?", &Options::options())
+    );
+    assert!(matches!(
+        to_rrules("0 0 12 * * ? 2026,2028", &Options::options()),
+        Err(ConversionError::Unsupported(_))
+    ));
+    assert!(matches!(
+        to_rrules("* * * ? * 5L", &Options::options()),
+        Err(ConversionError::Unsupported(_))
+    ));
+    assert!(matches!(
+        to_rrules("* * * * * * * *", &Options::options()),
+        Err(ConversionError::Parse(_))
+    ));
+}
+
+#[test]
+fn test_ics_export() {
+    let mut event = VEventOptions::new("backup@example.com", DateTime::new(2026, 10, 19, 12, 0, 0));
+    event.dtstamp = DateTime::new(2026, 10, 19, 8, 0, 0);
+    event.time_zone = Some("Europe/Berlin".to_string());
+    assert_eq!(
+        "BEGIN:VCALENDAR\r\n\
+         VERSION:2.0\r\n\
+         PRODID:-//cron-descriptor//EN\r\n\
+         CALSCALE:GREGORIAN\r\n\
+         BEGIN:VEVENT\r\n\
+         UID:backup@example.com\r\n\
+         DTSTAMP:20261019T080000Z\r\n\
+         DTSTART;TZID=Europe/Berlin:20261021T090000\r\n\
+         RRULE:FREQ=WEEKLY;BYDAY=MO,WE;BYHOUR=9;BYMINUTE=0\r\n\
+         SUMMARY:At 9:00 AM\\, only on Monday and Wednesday\r\n\
+         END:VEVENT\r\n\
+         END:VCALENDAR\r\n",
+        to_ics("0 9 * * MON,WED", &Options::options(), &event).unwrap()
+    );
+}
+
+#[test]
+fn test_ics_export_zoned_until() {
+    let mut event = VEventOptions::new("job", DateTime::new(2026, 1, 1, 0, 0, 0));
+    event.time_zone = Some("Europe/Berlin".to_string());
+    let ics = to_ics("0 0 12 * * ? 2026-2028", &Options::options(), &event).unwrap();
+    assert!(ics.contains("DTSTART;TZID=Europe/Berlin:20260101T120000\r\n"));
+    assert!(ics.contains("RRULE:FREQ=DAILY;BYHOUR=12;BYMINUTE=0;UNTIL=20281231T225959Z\r\n"));
+
+    event.time_zone = Some("America/New_York".to_string());
+    let ics = to_ics("0 0 12 * * ? 2026-2028", &Options::options(), &event).unwrap();
+    assert!(ics.contains(";UNTIL=20290101T045959Z\r\n"));
+
+    event.time_zone = Some("Mars/Olympus_Mons".to_string());
+    assert!(matches!(
+        to_ics("0 0 12 * * ? 2026-2028", &Options::options(), &event),
+        Err(ConversionError::Unsupported(_))
+    ));
+}
+
+#[test]
+fn test_ics_export_several_rules() {
+    let mut event = VEventOptions::new("job", DateTime::new(2026, 10, 19, 0, 0, 0));
+    event.dtstamp = DateTime::new(2026, 10, 19, 0, 0, 0);
+    event.summary = Some("Payroll; month start and Mondays".to_string());
+    let ics = to_ics("0 0 1 * MON", &Options::options(), &event).unwrap();
+    assert!(ics.contains("UID:job-1\r\nDTSTAMP:20261019T000000Z\r\nDTSTART:20261101T000000\r\n"));
+    assert!(ics.contains("UID:job-2\r\nDTSTAMP:20261019T000000Z\r\nDTSTART:20261019T000000\r\n"));
+    assert!(ics.contains("SUMMARY:Payroll\\; month start and Mondays\r\n"));
+}
+
+#[test]
+fn test_ics_folds_long_lines() {
+    let mut event = VEventOptions::new("job", DateTime::new(2026, 1, 1, 0, 0, 0));
+    event.summary = Some("x".repeat(100));
+    let ics = to_ics("0 0 * * *", &Options::options(), &event).unwrap();
+    assert!(ics.contains(&format!(
+        "SUMMARY:{}\r\n {}\r\n",
+        "x".repeat(67),
+        "x".repeat(33)
+    )));
+}
+
+#[test]
+fn test_ics_export_past_years() {
+    let event = VEventOptions::new("job", DateTime::new(2026, 1, 1, 0, 0, 0));
+    assert!(matches!(
+        to_ics("0 0 12 * * ? 2020", &Options::options(), &event),
+        Err(ConversionError::Unsupported(_))
+    ));
+}
+
+#[test]
+fn test_import_weekly_rrule() {
+    let import = from_rrule(
+        "FREQ=WEEKLY;BYDAY=MO,WE;BYHOUR=9;BYMINUTE=0",
+        &Options::options(),
+    )
+    .unwrap();
+    assert_eq!(vec!["0 9 * * MON,WED"], import.expressions);
+    assert_eq!(
+        vec!["At 9:00 AM, only on Monday and Wednesday"],
+        import.descriptions
+    );
+    assert_eq!(
+        vec!["0 9-12 * * MON-FRI"],
+        imported("RRULE:FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR;BYHOUR=9,10,11,12;BYMINUTE=0")
+    );
+}
+
+#[test]
+fn test_import_rrule_with_dtstart() {
+    assert_eq!(
+        vec!["30 2 * * SAT"],
+        imported("DTSTART:20260105T023000\nRRULE:FREQ=WEEKLY;BYDAY=SA")
+    );
+    assert_eq!(
+        vec!["30 2 * * MON"],
+        imported("DTSTART;TZID=Europe/Paris:20260105T023000\r\nRRULE:FREQ=WEEKLY")
+    );
+    assert_eq!(
+        vec!["0 8 15 3 *"],
+        imported("DTSTART:20260315T080000\nRRULE:FREQ=YEARLY")
+    );
+    assert_eq!(
+        vec!["15 6 1 3/3 *"],
+        imported("DTSTART:20260301T061500\nRRULE:FREQ=MONTHLY;INTERVAL=3")
+    );
+    assert_eq!(
+        vec!["0 0 8 15 3 ? 2026/2"],
+        imported("DTSTART:20260315T080000\nRRULE:FREQ=YEARLY;INTERVAL=2")
+    );
+}
+
+#[test]
+fn test_import_interval_rrule() {
+    assert_eq!(vec!["*/15 * * * *"], imported("FREQ=MINUTELY;INTERVAL=15"));
+    assert_eq!(
+        vec!["5/20 * * * * ?"],
+        imported("DTSTART:20260101T000005\nRRULE:FREQ=SECONDLY;INTERVAL=20")
+    );
+    assert_eq!(
+        vec!["0 */6 * * *"],
+        imported("FREQ=HOURLY;INTERVAL=6;BYMINUTE=0")
+    );
+    assert_eq!(
+        vec!["30 0 6 * * ?"],
+        imported("FREQ=DAILY;BYSECOND=30;BYMINUTE=0;BYHOUR=6")
+    );
+}
+
+#[test]
+fn test_import_monthly_rrule() {
+    assert_eq!(
+        vec!["0 10 * * TUE#1", "0 10 * * THU#3"],
+        imported("FREQ=MONTHLY;BYDAY=1TU,3TH;BYHOUR=10;BYMINUTE=0")
+    );
+    assert_eq!(
+        vec!["0 0 1,15 * *", "0 0 L * *"],
+        imported("FREQ=MONTHLY;BYMONTHDAY=1,15,-1;BYHOUR=0;BYMINUTE=0")
+    );
+    assert_eq!(
+        vec!["0 9 L-2 * *", "0 9 L * *"],
+        imported("FREQ=MONTHLY;BYMONTHDAY=-3,-1;BYHOUR=9;BYMINUTE=0")
+    );
+    assert_eq!(
+        vec!["0 22 LW * *"],
+        imported("FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1;BYHOUR=22;BYMINUTE=0")
+    );
+    assert_eq!(
+        vec!["0 9,17 * * FRIL"],
+        imported("FREQ=MONTHLY;BYDAY=FR;BYHOUR=9,17;BYMINUTE=0;BYSETPOS=-2,-1")
+    );
+}
+
+#[test]
+fn test_import_rrule_round_trip() {
+    for expression in [
+        "0 9 * * MON,WED",
+        "*/15 * * * *",
+        "0 0 1,15 * *",
+        "0 30 9 ? * SAT#2",
+        "0 0 9,17 ? * FRIL",
+        "0 0 0 LW * ?",
+        "0 0 0 L-2 * ?",
+        "0 12 * 1,7 *",
+    ] {
+        let rules = to_rrules(expression, &Options::options()).unwrap();
+        let round_trip: Vec<String> = rules.iter().flat_map(|r| imported(r)).collect();
+        assert_eq!(
+            rules,
+            round_trip
+                .iter()
+                .flat_map(|e| to_rrules(e, &Options::options()).unwrap())
+                .collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn test_import_unsupported_rrule() {
+    assert_eq!(
+        Err(ConversionError::Unsupported(
+            "FREQ=WEEKLY;INTERVAL=2 has no cron equivalent".to_string()
+        )),
+        from_rrule(
+            "DTSTART:20260104T020000Z\nRRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=SU",
+            &Options::options()
+        )
+    );
+    for rule in [
+        "FREQ=HOURLY;INTERVAL=5",
+        "FREQ=DAILY;COUNT=3",
+        "FREQ=DAILY;UNTIL=20261231T000000Z",
+        "FREQ=MONTHLY;BYMONTHDAY=13;BYDAY=FR",
+        "FREQ=MONTHLY;BYDAY=-2MO",
+        "FREQ=YEARLY;BYDAY=20MO",
+        "FREQ=WEEKLY",
+    ] {
+        assert!(matches!(
+            from_rrule(rule, &Options::options()),
+            Err(ConversionError::Unsupported(_))
+        ));
+    }
+    assert!(matches!(
+        from_rrule("FREQ=FORTNIGHTLY", &Options::options()),
+        Err(ConversionError::Parse(_))
+    ));
+    assert!(matches!(
+        from_rrule("BYDAY=MO", &Options::options()),
+        Err(ConversionError::Parse(_))
+    ));
+}
+
+#[test]
+fn test_wrap_around_ranges() {
+    assert_eq!(
+        vec!["FREQ=WEEKLY;BYDAY=MO,FR,SA,SU;BYHOUR=0,1,2,22,23;BYMINUTE=0"],
+        rrules("0 22-2 * * FRI-MON")
+    );
+    assert_eq!(
+        vec!["FREQ=DAILY;BYMONTH=1,2,11,12;BYHOUR=9;BYMINUTE=0"],
+        rrules("0 9 * NOV-FEB *")
+    );
+}
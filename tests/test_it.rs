@@ -0,0 +1,88 @@
+use cron_descriptor::cronparser::{Clock, Options, Verbosity};
+mod test_utils;
+use crate::test_utils::{unwrapped_description, unwrapped_description_options};
+
+#[ctor::ctor]
+fn init() {
+    rust_i18n::set_locale("it");
+}
+
+#[test]
+fn test_every_minute() {
+    assert_eq!("Ogni minuto", unwrapped_description("* * * * *"));
+    assert_eq!("Ogni secondo", unwrapped_description("*/1 * * * * *"));
+    assert_eq!("Ogni minuto tra le 11:00 e le 11:10", unwrapped_description("0-10 11 * * *"));
+    assert_eq!("Ogni minuto, alle 01:00", unwrapped_description("* 1 * * *"));
+}
+
+#[test]
+fn test_twenty_four_hour_times() {
+    assert_eq!("Alle 11:30", unwrapped_description("30 11 * * *"));
+    assert_eq!("Alle 23:00, da lunedì a venerdì", unwrapped_description("0 23 ? * MON-FRI"));
+    assert_eq!("Alle 14:30 e 16:30", unwrapped_description("30 14,16 * * *"));
+    assert_eq!("Tra le 00:00 e le 02:00", unwrapped_description("0 0-2 * * *"));
+    assert_eq!("Ogni 5 minuti tra le 09:00 e le 17:55", unwrapped_description("*/5 9-17 * * *"));
+}
+
+#[test]
+fn test_plurals() {
+    assert_eq!("A 1 secondo dall'inizio del minuto", unwrapped_description("1 * * * * *"));
+    assert_eq!("A 1 e 2 secondi dall'inizio del minuto", unwrapped_description("1,2 * * * * *"));
+    assert_eq!("Ogni 5 minuti", unwrapped_description("*/5 * * * *"));
+    assert_eq!("Ogni 2 ore", unwrapped_description("0 */2 * * *"));
+    assert_eq!("Alle 00:00, ogni 2 giorni", unwrapped_description("0 0 */2 * *"));
+    assert_eq!("Alle 09:00, 1 giorno prima dell'ultimo giorno del mese", unwrapped_description("0 9 L-1 * ?"));
+    assert_eq!("Alle 09:00, 2 giorni prima dell'ultimo giorno del mese", unwrapped_description("0 9 L-2 * ?"));
+}
+
+#[test]
+fn test_days_of_week() {
+    assert_eq!("Alle 09:00, da lunedì a venerdì", unwrapped_description("0 9 * * MON-FRI"));
+    assert_eq!("Alle 09:00, solo il martedì, il mercoledì e il venerdì", unwrapped_description("0 9 * * 2,3,5"));
+    assert_eq!("Alle 09:00, solo la domenica", unwrapped_description("0 9 * * 0"));
+    assert_eq!("Alle 09:00, ogni 2 giorni della settimana, iniziando il martedì", unwrapped_description("0 9 * * 2/2"));
+    assert_eq!("Alle 09:00, il giorno 1 del mese, o il martedì", unwrapped_description("0 9 1 * TUE"));
+}
+
+#[test]
+fn test_nth_and_last_days() {
+    assert_eq!("Alle 09:00, il primo lunedì del mese", unwrapped_description("0 9 ? * MON#1"));
+    assert_eq!("Alle 09:00, la prima domenica del mese", unwrapped_description("0 9 ? * SUN#1"));
+    assert_eq!("Alle 09:00, il terzo venerdì e il primo lunedì del mese", unwrapped_description("0 9 ? * FRI#3,MON#1"));
+    assert_eq!("Alle 09:00, l'ultimo venerdì del mese", unwrapped_description("0 9 ? * 5L"));
+    assert_eq!("Alle 09:00, l'ultimo giorno del mese", unwrapped_description("0 9 L * ?"));
+    assert_eq!("Alle 09:00, l'ultimo giorno feriale del mese", unwrapped_description("0 9 LW * ?"));
+    assert_eq!("Alle 09:00, il giorno feriale più vicino al giorno 15 del mese", unwrapped_description("0 9 15W * ?"));
+}
+
+#[test]
+fn test_months_and_years() {
+    assert_eq!("Alle 09:00, da gennaio a marzo", unwrapped_description("0 9 * JAN-MAR *"));
+    assert_eq!("Alle 09:00, solo a gennaio, a marzo e a luglio", unwrapped_description("0 9 * 1,3,7 *"));
+    assert_eq!("Alle 09:00, ogni 2 mesi, iniziando a marzo", unwrapped_description("0 9 * 3/2 *"));
+    assert_eq!("Alle 09:00, da 2025 a 2027", unwrapped_description("0 9 * * * 2025-2027"));
+}
+
+#[test]
+fn test_ordinal_days() {
+    let ordinal = Options { ordinal_days: true, ..Options::options() };
+    assert_eq!("Alle 00:00, il 1º e 15º del mese", unwrapped_description_options("0 0 1,15 * *", &ordinal));
+    assert_eq!("Alle 09:00, dal 1º al 10º del mese", unwrapped_description_options("0 9 1-10 * *", &ordinal));
+    assert_eq!("Alle 09:00, il 1º lunedì e il 3º venerdì del mese", unwrapped_description_options("0 9 ? * MON#1,FRI#3", &ordinal));
+}
+
+#[test]
+fn test_verbosity() {
+    let verbose = Options { verbosity: Verbosity::Verbose, ..Options::options() };
+    let compact = Options { verbosity: Verbosity::Compact, ..Options::options() };
+    let abbreviated = Options { abbreviated: true, ..compact };
+    assert_eq!("Alle 09:00, da lunedì a venerdì, ogni mese, ogni anno", unwrapped_description_options("0 9 * * MON-FRI", &verbose));
+    assert_eq!("Lun–ven 09:00", unwrapped_description_options("0 9 * * MON-FRI", &compact));
+    assert_eq!("Feriali 09:00–17:00, ogni 15 min", unwrapped_description_options("*/15 9-17 * * 1-5", &abbreviated));
+}
+
+#[test]
+fn test_twelve_hour_clock() {
+    let twelve_hour = Options { clock: Clock::TwelveHour, ..Options::options() };
+    assert_eq!("Alle 9:30 PM", unwrapped_description_options("30 21 * * *", &twelve_hour));
+}
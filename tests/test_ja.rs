@@ -0,0 +1,64 @@
+use cron_descriptor::cronparser::{Clock, Options, Verbosity};
+mod test_utils;
+use crate::test_utils::unwrapped_description_options;
+
+#[ctor::ctor]
+fn init() {
+    rust_i18n::set_locale("ja");
+}
+
+fn description(cron_expr: &str) -> String {
+    unwrapped_description_options(cron_expr, &Options::twenty_four_hour())
+}
+
+#[test]
+fn test_intervals() {
+    assert_eq!("毎分", description("* * * * *"));
+    assert_eq!("5分ごと", description("*/5 * * * *"));
+    assert_eq!("3時間ごと", description("0 */3 * * *"));
+    assert_eq!("09:00から17:55まで5分ごと", description("*/5 9-17 * * *"));
+    assert_eq!("毎時05と10分", description("5,10 * * * *"));
+}
+
+#[test]
+fn test_days_before_time() {
+    assert_eq!("11:30", description("30 11 * * *"));
+    assert_eq!("月曜日から金曜日まで、09:00", description("0 9 * * MON-FRI"));
+    assert_eq!("火曜日、水曜日と金曜日のみ、09:00", description("0 9 * * 2,3,5"));
+    assert_eq!("毎月第2水曜日、09:00", description("0 9 ? * WED#2"));
+    assert_eq!("毎月最終金曜日、09:00", description("0 9 ? * 5L"));
+    assert_eq!("毎月末日の3日前、09:00", description("0 9 L-3 * ?"));
+    assert_eq!("毎月1日、または月曜日、09:00", description("0 9 1 * MON"));
+}
+
+#[test]
+fn test_years_and_months_first() {
+    assert_eq!("1月、3月と7月のみ、09:00", description("0 9 * 1,3,7 *"));
+    assert_eq!("2025年のみ、09:00", description("0 9 * * * 2025"));
+    assert_eq!("2025から2027まで、1月から3月まで、09:00", description("0 9 * JAN-MAR * 2025-2027"));
+}
+
+#[test]
+fn test_ordinal_days() {
+    let opts = Options { ordinal_days: true, ..Options::twenty_four_hour() };
+    assert_eq!("毎月1日と15日、00:00", unwrapped_description_options("0 0 1,15 * *", &opts));
+    assert_eq!("毎月第2水曜日、09:00", unwrapped_description_options("0 9 ? * WED#2", &opts));
+}
+
+#[test]
+fn test_compact() {
+    let opts = Options {
+        verbosity: Verbosity::Compact,
+        abbreviated: true,
+        ..Options::twenty_four_hour()
+    };
+    assert_eq!("平日09:00–17:00、15分ごと", unwrapped_description_options("*/15 9-17 * * 1-5", &opts));
+}
+
+#[test]
+fn test_clock() {
+    assert_eq!("21:30", unwrapped_description_options("30 21 * * *", &Options::options()));
+    let opts = Options { clock: Clock::TwelveHour, ..Options::options() };
+    assert_eq!("午前9:00", unwrapped_description_options("0 9 * * *", &opts));
+    assert_eq!("月曜日のみ、午後9:30", unwrapped_description_options("30 21 * * MON", &opts));
+}
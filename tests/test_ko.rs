@@ -0,0 +1,29 @@
+use cron_descriptor::cronparser::Options;
+mod test_utils;
+use crate::test_utils::unwrapped_description_options;
+
+#[ctor::ctor]
+fn init() {
+    rust_i18n::set_locale("ko");
+}
+
+fn description(cron_expr: &str) -> String {
+    unwrapped_description_options(cron_expr, &Options::twenty_four_hour())
+}
+
+#[test]
+fn test_intervals() {
+    assert_eq!("매분", description("* * * * *"));
+    assert_eq!("5분마다", description("*/5 * * * *"));
+    assert_eq!("3시간마다", description("0 */3 * * *"));
+    assert_eq!("09:00부터 17:55까지 5분마다", description("*/5 9-17 * * *"));
+}
+
+#[test]
+fn test_days_before_time() {
+    assert_eq!("월요일부터 금요일까지, 09:00", description("0 9 * * MON-FRI"));
+    assert_eq!("화요일, 수요일, 금요일에만, 09:00", description("0 9 * * 2,3,5"));
+    assert_eq!("매월 둘째 수요일, 09:00", description("0 9 ? * WED#2"));
+    assert_eq!("매월 마지막 날 3일 전, 09:00", description("0 9 L-3 * ?"));
+    assert_eq!("1월부터 3월까지, 09:00", description("0 9 * JAN-MAR *"));
+}
@@ -0,0 +1,95 @@
+use std::path::Path;
+
+use cron_descriptor::cronparser::cron_expression_descriptor::{
+    get_description_cron_locale, get_description_cron_options_locale,
+};
+use cron_descriptor::cronparser::{NameLocale, Options};
+use cron_descriptor::locale::{self, LocaleError};
+
+const EN: &str = include_str!("../locales/en.yml");
+
+// Descriptions set the current locale, so everything described is in this one test.
+#[test]
+fn test_registered_locales_and_overrides() {
+    let pirate = EN
+        .replace("every minute", "every blessed minute")
+        .replace("at: At", "at: Come");
+    locale::register_locale("en-pirate", &pirate).unwrap();
+    assert!(locale::is_available("en-pirate"));
+    assert_eq!("Every blessed minute", get_description_cron_locale("* * * * *", "en-pirate").unwrap());
+    assert_eq!("Come 9:00 AM", get_description_cron_locale("0 9 * * *", "en-pirate").unwrap());
+
+    locale::override_message("es", "messages.every_minute", "cada minutito").unwrap();
+    assert_eq!("Cada minutito", get_description_cron_locale("* * * * *", "es").unwrap());
+    assert_eq!("Cada hora", get_description_cron_locale("0 * * * *", "es").unwrap());
+
+    // An optional key the locale does not have yet.
+    locale::override_message("en-pirate", "list_separator", "; ").unwrap();
+    assert_eq!(
+        "Come 9:00 AM, only on Monday; Wednesday and Friday",
+        get_description_cron_locale("0 9 * * 1,3,5", "en-pirate").unwrap()
+    );
+
+    // A name left empty is no name, so the `L` of the last day of the week stays Saturday.
+    let blank = EN.replace("  monday: Mon\n", "  monday: \"\"\n");
+    locale::register_locale("en-blank", &blank).unwrap();
+    let blank_names = Options {
+        name_locale: NameLocale::Locale("en-blank"),
+        ..Options::options()
+    };
+    assert_eq!(
+        "At 9:00 AM, only on Saturday",
+        get_description_cron_options_locale("0 9 ? * L", &blank_names, "en").unwrap()
+    );
+
+    // JSON is YAML too.
+    let json = format!(
+        "{{\"messages\": {{\"every_minute\": \"chaque minute\"}}, {}}}",
+        EN.lines()
+            .filter(|line| !line.starts_with(' ') && !line.ends_with(':') && !line.starts_with("at:"))
+            .map(|line| {
+                let (key, value) = line.split_once(": ").unwrap();
+                format!("\"{}\": \"{}\"", key, value)
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    match locale::register_locale("json", &json) {
+        Err(LocaleError::MissingKeys(keys)) => {
+            assert!(keys.contains(&"at".to_string()));
+            assert!(keys.contains(&"plurals.minute.other".to_string()));
+            assert!(!keys.contains(&"plurals.minute.one".to_string()));
+            assert!(!keys.contains(&"messages.every_minute".to_string()));
+            assert!(!keys.contains(&"monday".to_string()));
+        }
+        other => panic!("{:?}", other),
+    }
+    assert!(!locale::is_available("json"));
+}
+
+#[test]
+fn test_rejected_bundles_and_overrides() {
+    assert!(matches!(locale::register_locale("xx", "at: [1, 2]"), Err(LocaleError::Malformed(key)) if key == "at"));
+    assert!(matches!(locale::register_locale("xx", "just text"), Err(LocaleError::Malformed(key)) if key.is_empty()));
+    assert!(matches!(locale::register_locale("xx", "at: {"), Err(LocaleError::Yaml(_))));
+    assert!(matches!(
+        locale::register_locale_file("xx", Path::new("locales/xx.yml")),
+        Err(LocaleError::Io(..))
+    ));
+    assert!(matches!(
+        locale::override_message("xx", "messages.every_minute", "?"),
+        Err(LocaleError::UnknownLocale(_))
+    ));
+    assert!(matches!(
+        locale::override_message("fr", "messages.every_fortnight", "?"),
+        Err(LocaleError::UnknownKey(_))
+    ));
+    locale::override_message("fr", "genders.minute", "feminine").unwrap();
+    assert!(!locale::is_available("xx"));
+}
+
+#[test]
+fn test_register_locale_file() {
+    locale::register_locale_file("es-copy", Path::new("locales/es.yml")).unwrap();
+    assert!(locale::is_available("es-copy"));
+}
@@ -0,0 +1,61 @@
+use std::fs;
+
+use cron_descriptor::locale::{self, PlaceholderMismatch};
+
+const EN: &str = include_str!("../locales/en.yml");
+
+#[test]
+fn test_compiled_locales_are_complete() {
+    for entry in fs::read_dir("locales").unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_stem().unwrap().to_str().unwrap();
+        let report = locale::check_locale(name).unwrap();
+        assert!(report.is_complete(), "{:#?}", report);
+    }
+}
+
+#[test]
+fn test_check_registered_locale() {
+    let bundle = EN
+        .replace("every_x: every {0}", "every_x: every {1}")
+        .replace("the_last_x: the last {0}", "the_last_x: the {last} {0}")
+        .replace("between %{0}", "between {0}")
+        .replace("on_x: on {0}", "on_x: on {0}\n  on_x_feminine: on {day}\n  only_in_yaer: in {0}")
+        .replace("  day:\n    one: day", "  day:\n    few: a few days\n    one: day");
+    locale::register_locale("en-drifted", &bundle).unwrap();
+    let report = locale::check_locale("en-drifted").unwrap();
+    assert!(!report.is_complete());
+    assert!(report.missing_keys.is_empty());
+    assert_eq!(vec!["messages.only_in_yaer"], report.extra_keys);
+    assert_eq!(
+        vec![
+            PlaceholderMismatch {
+                key: "messages.every_minute_between".to_string(),
+                missing: vec!["%{0}".to_string()],
+                unexpected: vec!["{0}".to_string()],
+            },
+            PlaceholderMismatch {
+                key: "messages.every_x".to_string(),
+                missing: vec!["{0}".to_string()],
+                unexpected: vec!["{1}".to_string()],
+            },
+            PlaceholderMismatch {
+                key: "messages.on_x_feminine".to_string(),
+                missing: vec!["{0}".to_string()],
+                unexpected: vec!["{day}".to_string()],
+            },
+        ],
+        report.placeholder_mismatches
+    );
+
+    // Overrides are checked with the locale they override.
+    locale::override_message("en-drifted", "messages.every_x", "every {0}").unwrap();
+    let report = locale::check_locale("en-drifted").unwrap();
+    assert_eq!(2, report.placeholder_mismatches.len());
+}
+
+#[test]
+fn test_check_locales() {
+    assert!(locale::check_locales().iter().any(|report| report.locale == "pt"));
+    assert_eq!(None, locale::check_locale("xx-unknown"));
+}
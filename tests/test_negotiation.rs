@@ -0,0 +1,78 @@
+use cron_descriptor::cronparser::cron_expression_descriptor::get_description_cron_locale;
+use cron_descriptor::locale::{self, LanguageTag};
+
+fn tag(language: &str, script: Option<&str>, region: Option<&str>) -> Option<LanguageTag> {
+    Some(LanguageTag {
+        language: language.to_string(),
+        script: script.map(str::to_string),
+        region: region.map(str::to_string),
+    })
+}
+
+#[test]
+fn test_parse_language_tags() {
+    assert_eq!(tag("pt", None, Some("BR")), LanguageTag::parse("pt-BR"));
+    assert_eq!(tag("pt", None, Some("BR")), LanguageTag::parse("pt_br"));
+    assert_eq!(tag("es", None, Some("419")), LanguageTag::parse("es-419"));
+    assert_eq!(tag("zh", Some("Hant"), Some("TW")), LanguageTag::parse("zh-hant-tw"));
+    assert_eq!(tag("de", None, Some("CH")), LanguageTag::parse("de-CH-1996"));
+    assert_eq!(tag("en", None, None), LanguageTag::parse("EN"));
+    assert_eq!(None, LanguageTag::parse("*"));
+    assert_eq!(None, LanguageTag::parse("x-klingon"));
+    assert_eq!(None, LanguageTag::parse("english"));
+    assert_eq!(None, LanguageTag::parse("pt-"));
+    assert_eq!("zh-Hant-TW", LanguageTag::parse("ZH_HANT_tw").unwrap().to_string());
+}
+
+#[test]
+fn test_fallback_chains() {
+    let chain = |tag: &str| LanguageTag::parse(tag).unwrap().fallback_chain();
+    assert_eq!(vec!["pt-BR", "pt", "en"], chain("pt-BR"));
+    assert_eq!(vec!["es-419", "es", "en"], chain("es-419"));
+    assert_eq!(
+        vec!["zh-Hant-TW", "zh-Hant", "zh-TW", "zh", "zh-CN", "en"],
+        chain("zh-Hant-TW")
+    );
+    assert_eq!(vec!["en-GB", "en"], chain("en-GB"));
+}
+
+#[test]
+fn test_resolve() {
+    assert_eq!("pt", locale::resolve("pt-BR"));
+    assert_eq!("es", locale::resolve("es-419"));
+    assert_eq!("zh-TW", locale::resolve("zh-Hant-TW"));
+    assert_eq!("zh-TW", locale::resolve("zh-HK"));
+    assert_eq!("zh-CN", locale::resolve("zh-Hans-SG"));
+    assert_eq!("zh-CN", locale::resolve("zh_cn"));
+    assert_eq!("en", locale::resolve("tlh"));
+    assert_eq!("en", locale::resolve("not a locale"));
+}
+
+#[test]
+fn test_negotiate() {
+    assert_eq!("fr", locale::negotiate("fr-CH, fr;q=0.9, en;q=0.8, de;q=0.7, *;q=0.5"));
+    assert_eq!("de", locale::negotiate("sv;q=0.9, de-AT;q=0.8, en;q=0.7"));
+    assert_eq!("es", locale::negotiate("en;q=0.5, es-419"));
+    assert_eq!("it", locale::negotiate("fr;q=0, it;q=0.1"));
+    assert_eq!("en", locale::negotiate("sv, fi;q=0.8, tlh"));
+    assert_eq!("en", locale::negotiate(""));
+    assert_eq!("ja", locale::negotiate("ja-JP,ja;q=0.9"));
+}
+
+#[test]
+fn test_available_locales() {
+    let locales = locale::available_locales();
+    for expected in ["en", "es", "pt", "de", "fr", "zh-CN", "zh-TW", "ar"] {
+        assert!(locales.contains(&expected.to_string()), "{}", expected);
+    }
+    assert!(locales.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+// Descriptions set the current locale, so everything described is in this one test.
+#[test]
+fn test_descriptions_in_fallback_locales() {
+    assert_eq!("Cada minuto", get_description_cron_locale("* * * * *", "es-419").unwrap());
+    assert_eq!("A cada minuto", get_description_cron_locale("* * * * *", "pt-BR").unwrap());
+    assert_eq!("每分鐘", get_description_cron_locale("* * * * *", "zh-Hant-TW").unwrap());
+    assert_eq!("Every minute", get_description_cron_locale("* * * * *", "tlh").unwrap());
+}
@@ -0,0 +1,88 @@
+use cron_descriptor::cronparser::{Clock, Options, Verbosity};
+mod test_utils;
+use crate::test_utils::{unwrapped_description, unwrapped_description_options};
+
+#[ctor::ctor]
+fn init() {
+    rust_i18n::set_locale("nl");
+}
+
+#[test]
+fn test_every_minute() {
+    assert_eq!("Elke minuut", unwrapped_description("* * * * *"));
+    assert_eq!("Elke seconde", unwrapped_description("*/1 * * * * *"));
+    assert_eq!("Elke minuut tussen 11:00 en 11:10", unwrapped_description("0-10 11 * * *"));
+    assert_eq!("Elke minuut, om 01:00", unwrapped_description("* 1 * * *"));
+}
+
+#[test]
+fn test_twenty_four_hour_times() {
+    assert_eq!("Om 11:30", unwrapped_description("30 11 * * *"));
+    assert_eq!("Om 23:00, maandag tot en met vrijdag", unwrapped_description("0 23 ? * MON-FRI"));
+    assert_eq!("Om 14:30 en 16:30", unwrapped_description("30 14,16 * * *"));
+    assert_eq!("Tussen 00:00 en 02:00", unwrapped_description("0 0-2 * * *"));
+    assert_eq!("Elke 5 minuten tussen 09:00 en 17:55", unwrapped_description("*/5 9-17 * * *"));
+}
+
+#[test]
+fn test_plurals() {
+    assert_eq!("Om 1 seconde na de minuut", unwrapped_description("1 * * * * *"));
+    assert_eq!("Om 1 en 2 seconden na de minuut", unwrapped_description("1,2 * * * * *"));
+    assert_eq!("Elke 5 minuten", unwrapped_description("*/5 * * * *"));
+    assert_eq!("Elke 2 uur", unwrapped_description("0 */2 * * *"));
+    assert_eq!("Om 00:00, elke 2 dagen", unwrapped_description("0 0 */2 * *"));
+    assert_eq!("Om 09:00, 1 dag voor de laatste dag van de maand", unwrapped_description("0 9 L-1 * ?"));
+    assert_eq!("Om 09:00, 2 dagen voor de laatste dag van de maand", unwrapped_description("0 9 L-2 * ?"));
+}
+
+#[test]
+fn test_days_of_week() {
+    assert_eq!("Om 09:00, maandag tot en met vrijdag", unwrapped_description("0 9 * * MON-FRI"));
+    assert_eq!("Om 09:00, alleen op dinsdag, woensdag en vrijdag", unwrapped_description("0 9 * * 2,3,5"));
+    assert_eq!("Om 09:00, alleen op zondag", unwrapped_description("0 9 * * 0"));
+    assert_eq!("Om 09:00, elke 2 dagen van de week, beginnend op dinsdag", unwrapped_description("0 9 * * 2/2"));
+    assert_eq!("Om 09:00, op dag 1 van de maand, of op dinsdag", unwrapped_description("0 9 1 * TUE"));
+}
+
+#[test]
+fn test_nth_and_last_days() {
+    assert_eq!("Om 09:00, op de eerste maandag van de maand", unwrapped_description("0 9 ? * MON#1"));
+    assert_eq!("Om 09:00, op de eerste zondag van de maand", unwrapped_description("0 9 ? * SUN#1"));
+    assert_eq!("Om 09:00, op de derde vrijdag en de eerste maandag van de maand", unwrapped_description("0 9 ? * FRI#3,MON#1"));
+    assert_eq!("Om 09:00, op de laatste vrijdag van de maand", unwrapped_description("0 9 ? * 5L"));
+    assert_eq!("Om 09:00, op de laatste dag van de maand", unwrapped_description("0 9 L * ?"));
+    assert_eq!("Om 09:00, op de laatste werkdag van de maand", unwrapped_description("0 9 LW * ?"));
+    assert_eq!("Om 09:00, op de werkdag het dichtst bij dag 15 van de maand", unwrapped_description("0 9 15W * ?"));
+}
+
+#[test]
+fn test_months_and_years() {
+    assert_eq!("Om 09:00, januari tot en met maart", unwrapped_description("0 9 * JAN-MAR *"));
+    assert_eq!("Om 09:00, alleen in januari, maart en juli", unwrapped_description("0 9 * 1,3,7 *"));
+    assert_eq!("Om 09:00, elke 2 maanden, beginnend in maart", unwrapped_description("0 9 * 3/2 *"));
+    assert_eq!("Om 09:00, 2025 tot en met 2027", unwrapped_description("0 9 * * * 2025-2027"));
+}
+
+#[test]
+fn test_ordinal_days() {
+    let ordinal = Options { ordinal_days: true, ..Options::options() };
+    assert_eq!("Om 00:00, op de 1e en 15e van de maand", unwrapped_description_options("0 0 1,15 * *", &ordinal));
+    assert_eq!("Om 09:00, tussen de 1e en de 10e van de maand", unwrapped_description_options("0 9 1-10 * *", &ordinal));
+    assert_eq!("Om 09:00, op de 1e maandag en de 3e vrijdag van de maand", unwrapped_description_options("0 9 ? * MON#1,FRI#3", &ordinal));
+}
+
+#[test]
+fn test_verbosity() {
+    let verbose = Options { verbosity: Verbosity::Verbose, ..Options::options() };
+    let compact = Options { verbosity: Verbosity::Compact, ..Options::options() };
+    let abbreviated = Options { abbreviated: true, ..compact };
+    assert_eq!("Om 09:00, maandag tot en met vrijdag, elke maand, elk jaar", unwrapped_description_options("0 9 * * MON-FRI", &verbose));
+    assert_eq!("Ma–vr 09:00", unwrapped_description_options("0 9 * * MON-FRI", &compact));
+    assert_eq!("Werkdagen 09:00–17:00, elke 15 min", unwrapped_description_options("*/15 9-17 * * 1-5", &abbreviated));
+}
+
+#[test]
+fn test_twelve_hour_clock() {
+    let twelve_hour = Options { clock: Clock::TwelveHour, ..Options::options() };
+    assert_eq!("Om 9:30 PM", unwrapped_description_options("30 21 * * *", &twelve_hour));
+}
@@ -0,0 +1,64 @@
+use cron_descriptor::cronparser::Options;
+mod test_utils;
+use crate::test_utils::unwrapped_description_options;
+
+#[ctor::ctor]
+fn init() {
+    rust_i18n::set_locale("pl");
+}
+
+fn description(cron_expr: &str) -> String {
+    unwrapped_description_options(cron_expr, &Options::twenty_four_hour())
+}
+
+#[test]
+fn test_every_minute() {
+    assert_eq!("Co minutę", description("* * * * *"));
+    assert_eq!("Co sekundę", description("*/1 * * * * *"));
+}
+
+#[test]
+fn test_plurals() {
+    assert_eq!("Co 2 minuty", description("*/2 * * * *"));
+    assert_eq!("Co 5 minut", description("*/5 * * * *"));
+    assert_eq!("Co 12 minut", description("*/12 * * * *"));
+    assert_eq!("Co 21 minut", description("*/21 * * * *"));
+    assert_eq!("Co 22 minuty", description("*/22 * * * *"));
+    assert_eq!("1 sekundę po pełnej minucie", description("1 * * * * *"));
+    assert_eq!("O 09:00, 1 dzień przed ostatnim dniem miesiąca", description("0 9 L-1 * ?"));
+    assert_eq!("O 09:00, 5 dni przed ostatnim dniem miesiąca", description("0 9 L-5 * ?"));
+}
+
+#[test]
+fn test_day_of_week_cases() {
+    assert_eq!("O 09:00, od poniedziałku do piątku", description("0 9 * * MON-FRI"));
+    assert_eq!("O 09:00, tylko we wtorek, w środę i w piątek", description("0 9 * * 2,3,5"));
+    assert_eq!("O 09:00, co 2 dni tygodnia, począwszy od środy", description("0 9 * * 3/2"));
+}
+
+#[test]
+fn test_nth_and_last_day_of_week() {
+    assert_eq!("O 09:00, w drugi poniedziałek miesiąca", description("0 9 ? * MON#2"));
+    assert_eq!("O 09:00, w drugą środę miesiąca", description("0 9 ? * WED#2"));
+    assert_eq!("O 09:00, w ostatni piątek miesiąca", description("0 9 ? * 5L"));
+    assert_eq!("O 09:00, w ostatnią niedzielę miesiąca", description("0 9 ? * 0L"));
+}
+
+#[test]
+fn test_month_cases() {
+    assert_eq!("O 09:00, od stycznia do marca", description("0 9 * JAN-MAR *"));
+    assert_eq!("O 09:00, tylko w styczniu, w marcu i w lipcu", description("0 9 * 1,3,7 *"));
+}
+
+#[test]
+fn test_ordinal_days() {
+    let opts = Options { ordinal_days: true, ..Options::twenty_four_hour() };
+    assert_eq!("O 09:00, w 2. środę miesiąca", unwrapped_description_options("0 9 ? * WED#2", &opts));
+}
+
+#[test]
+fn test_wrapped_ranges() {
+    assert_eq!("O 50–59 i 00–10 minuty po pełnej godzinie", description("50-10 * * * *"));
+    assert_eq!("O 05, 50–59 i 00–10 minuty po pełnej godzinie", description("5,50-10 * * * *"));
+    assert_eq!("O 00:00, 28–31 i 1–3 dnia miesiąca", description("0 0 28-3 * ?"));
+}
@@ -3,7 +3,7 @@ extern crate strfmt;
 
 use cron_descriptor::cronparser::cron_expression_descriptor;
 use cron_descriptor::cronparser::cron_expression_descriptor::ParseException;
-use cron_descriptor::cronparser::Options;
+use cron_descriptor::cronparser::{Clock, NameLocale, Options, Verbosity};
 mod test_utils;
 use crate::test_utils::unwrapped_description;
 use crate::test_utils::unwrapped_description_options;
@@ -69,7 +69,7 @@ fn test_every45seconds() {
 #[test]
 fn test_minute_span() {
     assert_eq!(
-        "A cada minuto entre 11:00 AM e 11:10 AM",
+        "A cada minuto entre 11:00 e 11:10",
         cron_expression_descriptor::get_description_cron("0-10 11 * * *").unwrap()
     );
     assert_eq!(
@@ -77,11 +77,11 @@ fn test_minute_span() {
         unwrapped_description_options("0-10 11 * * *", &Options::twenty_four_hour())
     );
     assert_eq!(
-        "A cada minuto, à(s) 1:00 AM",
+        "A cada minuto, à(s) 01:00",
         cron_expression_descriptor::get_description_cron("* 1 * * *").unwrap()
     );
     assert_eq!(
-        "A cada minuto, à(s) 12:00 AM",
+        "A cada minuto, à(s) 00:00",
         cron_expression_descriptor::get_description_cron("* 0 * * *").unwrap()
     );
 }
@@ -127,18 +127,18 @@ fn test_every_xminutes() {
 
 #[test]
 fn test_daily_at_time() {
-    assert_eq!("Às 11:30 AM", unwrapped_description("30 11 * * *"));
+    assert_eq!("Às 11:30", unwrapped_description("30 11 * * *"));
     assert_eq!(
         "Às 11:30",
         unwrapped_description_options("30 11 * * *", &Options::twenty_four_hour())
     );
-    assert_eq!("Às 11:00 AM", unwrapped_description("0 11 * * *"));
+    assert_eq!("Às 11:00", unwrapped_description("0 11 * * *"));
 }
 
 #[test]
 fn test_time_of_day_certain_days_of_week() {
     assert_eq!(
-        "Às 11:00 PM, Segunda a Sexta",
+        "Às 23:00, Segunda a Sexta",
         unwrapped_description("0 23 ? * MON-FRI")
     );
     assert_eq!(
@@ -146,7 +146,7 @@ fn test_time_of_day_certain_days_of_week() {
         unwrapped_description_options("0 23 ? * MON-FRI", &Options::twenty_four_hour())
     );
     assert_eq!(
-        "Às 11:30 AM, Segunda a Sexta",
+        "Às 11:30, Segunda a Sexta",
         unwrapped_description("30 11 * * 1-5")
     );
 }
@@ -170,7 +170,7 @@ fn test_two_months_only() {
 #[test]
 fn test_two_times_each_afternoon() {
     assert_eq!(
-        "Às 2:30 PM e 4:30 PM",
+        "Às 14:30 e 16:30",
         unwrapped_description("30 14,16 * * *")
     );
     assert_eq!(
@@ -182,7 +182,7 @@ fn test_two_times_each_afternoon() {
 #[test]
 fn test_three_times_daily() {
     assert_eq!(
-        "Às 6:30 AM, 2:30 PM e 4:30 PM",
+        "Às 06:30, 14:30 e 16:30",
         unwrapped_description("30 6,14,16 * * *")
     );
     assert_eq!(
@@ -194,19 +194,19 @@ fn test_three_times_daily() {
 #[test]
 fn test_once_aweek() {
     assert_eq!(
-        "Às 9:46 AM, somente Domingo",
+        "Às 09:46, somente Domingo",
         unwrapped_description("46 9 * * 0")
     );
     assert_eq!(
-        "Às 9:46 AM, somente Domingo",
+        "Às 09:46, somente Domingo",
         unwrapped_description("46 9 * * 7")
     );
     assert_eq!(
-        "Às 9:46 AM, somente Segunda",
+        "Às 09:46, somente Segunda",
         unwrapped_description("46 9 * * 1")
     );
     assert_eq!(
-        "Às 9:46 AM, somente Sábado",
+        "Às 09:46, somente Sábado",
         unwrapped_description("46 9 * * 6")
     );
 }
@@ -218,15 +218,15 @@ fn test_once_aweek_non_zero_based() {
         ..Options::options()
     };
     assert_eq!(
-        "Às 9:46 AM, somente Domingo",
+        "Às 09:46, somente Domingo",
         unwrapped_description_options("46 9 * * 1", &options)
     );
     assert_eq!(
-        "Às 9:46 AM, somente Segunda",
+        "Às 09:46, somente Segunda",
         unwrapped_description_options("46 9 * * 2", &options)
     );
     assert_eq!(
-        "Às 9:46 AM, somente Sábado",
+        "Às 09:46, somente Sábado",
         unwrapped_description_options("46 9 * * 7", &options)
     );
 }
@@ -234,15 +234,15 @@ fn test_once_aweek_non_zero_based() {
 #[test]
 fn test_twice_aweek() {
     assert_eq!(
-        "Às 9:46 AM, somente Segunda e Terça",
+        "Às 09:46, somente Segunda e Terça",
         unwrapped_description("46 9 * * 1,2")
     );
     assert_eq!(
-        "Às 9:46 AM, somente Domingo e Sábado",
+        "Às 09:46, somente Domingo e Sábado",
         unwrapped_description("46 9 * * 0,6")
     );
     assert_eq!(
-        "Às 9:46 AM, somente Sábado e Domingo",
+        "Às 09:46, somente Sábado e Domingo",
         unwrapped_description("46 9 * * 6,7")
     );
 }
@@ -254,11 +254,11 @@ fn test_twice_aweek_non_zero_based() {
         ..Options::options()
     };
     assert_eq!(
-        "Às 9:46 AM, somente Domingo e Segunda",
+        "Às 09:46, somente Domingo e Segunda",
         unwrapped_description_options("46 9 * * 1,2", &options)
     );
     assert_eq!(
-        "Às 9:46 AM, somente Sexta e Sábado",
+        "Às 09:46, somente Sexta e Sábado",
         unwrapped_description_options("46 9 * * 6,7", &options)
     );
 }
@@ -266,7 +266,7 @@ fn test_twice_aweek_non_zero_based() {
 #[test]
 fn test_day_of_month() {
     assert_eq!(
-        "Às 12:23 PM, no dia 15 do mês",
+        "Às 12:23, no dia 15 do mês",
         unwrapped_description("23 12 15 * *")
     );
     assert_eq!(
@@ -278,7 +278,7 @@ fn test_day_of_month() {
 #[test]
 fn test_month_name() {
     assert_eq!(
-        "Às 12:23 PM, somente em Janeiro",
+        "Às 12:23, somente em Janeiro",
         unwrapped_description("23 12 * JAN *")
     );
 }
@@ -286,7 +286,7 @@ fn test_month_name() {
 #[test]
 fn test_day_of_month_with_question_mark() {
     assert_eq!(
-        "Às 12:23 PM, somente em Janeiro",
+        "Às 12:23, somente em Janeiro",
         unwrapped_description("23 12 ? JAN *")
     );
 }
@@ -294,7 +294,7 @@ fn test_day_of_month_with_question_mark() {
 #[test]
 fn test_month_name_range2() {
     assert_eq!(
-        "Às 12:23 PM, Janeiro a Fevereiro",
+        "Às 12:23, Janeiro a Fevereiro",
         unwrapped_description("23 12 * JAN-FEB *")
     );
 }
@@ -302,7 +302,7 @@ fn test_month_name_range2() {
 #[test]
 fn test_month_name_range3() {
     assert_eq!(
-        "Às 12:23 PM, Janeiro a Março",
+        "Às 12:23, Janeiro a Março",
         unwrapped_description("23 12 * JAN-MAR *")
     );
 }
@@ -310,7 +310,7 @@ fn test_month_name_range3() {
 #[test]
 fn test_month_name_ranges() {
     assert_eq!(
-        "Às 3:00 AM, somente em Janeiro a Março e Maio a Junho",
+        "Às 03:00, somente em Janeiro a Março e Maio a Junho",
         unwrapped_description("0 0 3 * 1-3,5-6 *")
     );
 }
@@ -318,7 +318,7 @@ fn test_month_name_ranges() {
 #[test]
 fn test_day_of_week_name() {
     assert_eq!(
-        "Às 12:23 PM, somente Domingo",
+        "Às 12:23, somente Domingo",
         unwrapped_description("23 12 * * SUN")
     );
 }
@@ -326,15 +326,15 @@ fn test_day_of_week_name() {
 #[test]
 fn test_day_of_week_range() {
     assert_eq!(
-        "A cada 5 minutos, à(s) 3:00 PM, Segunda a Sexta",
+        "A cada 5 minutos entre 15:00 e 15:55, Segunda a Sexta",
         unwrapped_description("*/5 15 * * MON-FRI")
     );
     assert_eq!(
-        "A cada 5 minutos, à(s) 3:00 PM, Domingo a Sábado",
+        "A cada 5 minutos entre 15:00 e 15:55, Domingo a Sábado",
         unwrapped_description("*/5 15 * * 0-6")
     );
     assert_eq!(
-        "A cada 5 minutos, à(s) 3:00 PM, Sábado a Domingo",
+        "A cada 5 minutos entre 15:00 e 15:55, Sábado a Domingo",
         unwrapped_description("*/5 15 * * 6-7")
     );
 }
@@ -342,7 +342,7 @@ fn test_day_of_week_range() {
 #[test]
 fn test_day_of_week_ranges() {
     assert_eq!(
-        "Às 3:00 AM, somente Domingo, Terça a Quinta e Sábado",
+        "Às 03:00, somente Domingo, Terça a Quinta e Sábado",
         unwrapped_description("0 0 3 * * 0,2-4,6")
     );
 }
@@ -381,7 +381,7 @@ fn test_last_day_of_the_month() {
 
 #[test]
 fn test_time_of_day_with_seconds() {
-    assert_eq!("Às 2:02:30 PM", unwrapped_description("30 02 14 * * *"));
+    assert_eq!("Às 14:02:30", unwrapped_description("30 02 14 * * *"));
 }
 
 #[test]
@@ -395,7 +395,7 @@ fn test_second_intervals() {
 #[test]
 fn test_second_minutes_hours_intervals() {
     assert_eq!(
-        "Segundos 5 a 10 após o minuto, de 30 a 35 minutos após a hora, entre 10:00 AM e 12:00 PM",
+        "Segundos 5 a 10 após o minuto, de 30 a 35 minutos após a hora, entre 10:00 e 12:00",
         unwrapped_description("5-10 30-35 10-12 * * *")
     );
 }
@@ -411,7 +411,7 @@ fn test_every5minutes_at30seconds() {
 #[test]
 fn test_minutes_past_the_hour_range() {
     assert_eq!(
-        "À(s) 30 minutos após a hora, entre 10:00 AM e 1:00 PM, somente Quarta e Sexta",
+        "À(s) 30 minutos após a hora, entre 10:00 e 13:00, somente Quarta e Sexta",
         unwrapped_description("0 30 10-13 ? * WED,FRI")
     );
 }
@@ -426,13 +426,13 @@ fn test_seconds_past_the_minute_interval() {
 
 #[test]
 fn test_between_with_interval() {
-    assert_eq!("A cada 3 minutos, de 02 a 59 minutos após a hora, à(s) 1:00 AM, 9:00 AM e 10:00 PM, entre os dias 11 e 26 do mês, Janeiro a Junho",
+    assert_eq!("A cada 3 minutos, de 02 a 59 minutos após a hora, à(s) 01:00, 09:00 e 22:00, entre os dias 11 e 26 do mês, Janeiro a Junho",
                    unwrapped_description("2-59/3 1,9,22 11-26 1-6 ?"));
 }
 
 #[test]
 fn test_recurring_first_of_month() {
-    assert_eq!("Às 6:00 AM", unwrapped_description("0 0 6 1/1 * ?"));
+    assert_eq!("Às 06:00", unwrapped_description("0 0 6 1/1 * ?"));
 }
 
 #[test]
@@ -460,7 +460,7 @@ fn test_every_past_the_hour() {
 #[test]
 fn test_every_xminute_past_the_hour_with_interval() {
     assert_eq!(
-        "A cada 2 minutos, de 00 a 30 minutos após a hora, à(s) 5:00 PM, Segunda a Sexta",
+        "A cada 2 minutos entre 17:00 e 17:30, Segunda a Sexta",
         unwrapped_description("0 0-30/2 17 ? * MON-FRI")
     );
 }
@@ -495,7 +495,7 @@ fn test_two_years_only() {
 #[test]
 fn test_year_range2() {
     assert_eq!(
-        "Às 12:23 PM, Janeiro a Fevereiro, 2013 a 2014",
+        "Às 12:23, Janeiro a Fevereiro, 2013 a 2014",
         unwrapped_description("23 12 * JAN-FEB * 2013-2014")
     );
 }
@@ -503,7 +503,7 @@ fn test_year_range2() {
 #[test]
 fn test_year_range3() {
     assert_eq!(
-        "Às 12:23 PM, Janeiro a Março, 2013 a 2015",
+        "Às 12:23, Janeiro a Março, 2013 a 2015",
         unwrapped_description("23 12 * JAN-MAR * 2013-2015")
     );
 }
@@ -515,7 +515,7 @@ fn test_issue26() {
         unwrapped_description("5,10 * * * *")
     );
     assert_eq!(
-        "À(s) 05 e 10 minutos após a hora, à(s) 12:00 AM",
+        "Às 00:05 e 00:10",
         unwrapped_description("5,10 0 * * *")
     );
     assert_eq!(
@@ -523,7 +523,7 @@ fn test_issue26() {
         unwrapped_description("5,10 * 2 * *")
     );
     assert_eq!(
-        "A cada 10 minutos, no dia 2 do mês",
+        "A cada 10 minutos, começando à(s) 5 minutos após a hora, no dia 2 do mês",
         unwrapped_description("5/10 * 2 * *")
     );
 
@@ -532,7 +532,7 @@ fn test_issue26() {
         unwrapped_description("5,6 0 * * * *")
     );
     assert_eq!(
-        "Aos 5 e 6 segundos após o minuto, à(s) 1:00 AM",
+        "Às 01:00:05 e 01:00:06",
         unwrapped_description("5,6 0 1 * * *")
     );
     assert_eq!(
@@ -547,3 +547,233 @@ fn test_issue26() {
 // use rust_i18n::set_locale;
 // i18n!("locales");
 // rust_i18n::set_locale("es");
+
+#[test]
+fn test_month_names() {
+    test_utils::assert_month_names_like_numbers();
+}
+
+#[test]
+fn test_day_of_week_names() {
+    test_utils::assert_day_of_week_names_like_numbers();
+}
+
+#[test]
+fn test_names_in_any_case() {
+    assert_eq!("Às 09:00, somente em Dezembro", unwrapped_description("0 9 * DEC *"));
+    assert_eq!(
+        "Às 09:00, somente em Janeiro e Março a Maio",
+        unwrapped_description("0 9 * jan,MAR-May *")
+    );
+    assert_eq!(
+        "Às 09:00, somente Sábado e Domingo",
+        unwrapped_description("0 9 * * sat,SUN")
+    );
+    assert_eq!(
+        "Às 09:00, Segunda a Sexta",
+        unwrapped_description("0 9 * * Monday-Friday")
+    );
+    assert_eq!(
+        "Às 09:00, no(a) último(a) Sexta do mês",
+        unwrapped_description("0 9 ? * FRIL")
+    );
+}
+
+#[test]
+fn test_offset_steps() {
+    assert_eq!(
+        "A cada 15 minutos, começando à(s) 5 minutos após a hora",
+        unwrapped_description("5/15 * * * *")
+    );
+    assert_eq!(
+        "A cada 10 segundos, começando aos 3 segundos após o minuto",
+        unwrapped_description("3/10 * * * * *")
+    );
+    assert_eq!(
+        "Às 09:00, a cada 10 dias, começando no dia 5 do mês",
+        unwrapped_description("0 9 5/10 * *")
+    );
+    assert_eq!(
+        "Às 09:00, a cada 2 meses, começando em Março",
+        unwrapped_description("0 9 * 3/2 *")
+    );
+    assert_eq!(
+        "Às 09:00, a cada 2 anos, começando em 2025",
+        unwrapped_description("0 0 9 * * ? 2025/2")
+    );
+}
+
+#[test]
+fn test_last_day_offsets_and_nearest_weekday() {
+    assert_eq!(
+        "Às 09:00, 3 dias antes do último dia do mês",
+        unwrapped_description("0 9 L-3 * ?")
+    );
+    assert_eq!(
+        "Às 09:00, 1 dia antes do último dia do mês",
+        unwrapped_description("0 9 L-1 * ?")
+    );
+    assert_eq!(
+        "Às 09:00, no dia da semana mais próximo 15 do mês",
+        unwrapped_description("0 9 15W * ?")
+    );
+}
+
+#[test]
+fn test_list_of_occurrences_in_the_month() {
+    assert_eq!(
+        "Às 09:00, no(a) primeiro(a) Terça e terceiro(a) Quinta do mês",
+        unwrapped_description("0 9 ? * TUE#1,THU#3")
+    );
+    assert_eq!(
+        "Às 09:00, no(a) último(a) Segunda e último(a) Sexta do mês",
+        unwrapped_description("0 9 ? * 1L,5L")
+    );
+}
+
+#[test]
+fn test_wrap_around_ranges() {
+    assert_eq!(
+        "Entre 22:00 e 02:00 durante a noite",
+        unwrapped_description("0 22-2 * * *")
+    );
+    assert_eq!(
+        "Entre 00:00 e 02:00 e entre 22:00 e 23:00, somente Sexta",
+        unwrapped_description("0 22-2 * * FRI")
+    );
+}
+
+#[test]
+fn test_day_of_month_or_day_of_week() {
+    assert_eq!(
+        "Às 00:00, no dia 1 e 15 do mês, ou no(a) Segunda",
+        unwrapped_description("0 0 1,15 * MON")
+    );
+}
+
+#[test]
+fn test_listed_times() {
+    assert_eq!(
+        "Às 09:00, 09:30, 17:00 e 17:30",
+        unwrapped_description("0,30 9,17 * * *")
+    );
+}
+
+#[test]
+fn test_steps_within_windows() {
+    assert_eq!(
+        "A cada 5 minutos entre 09:00 e 17:55, Segunda a Sexta",
+        unwrapped_description("*/5 9-17 * * MON-FRI")
+    );
+    assert_eq!(
+        "A cada minuto entre 09:00 e 09:30 e entre 17:00 e 17:30",
+        unwrapped_description("0-30 9,17 * * *")
+    );
+}
+
+#[test]
+fn test_verbosity() {
+    let verbose = Options {
+        verbosity: Verbosity::Verbose,
+        ..Options::options()
+    };
+    assert_eq!(
+        "Às 09:00, todo dia, todo mês, todos os anos",
+        unwrapped_description_options("0 9 * * *", &verbose)
+    );
+    let compact = Options {
+        verbosity: Verbosity::Compact,
+        ..Options::options()
+    };
+    assert_eq!(
+        "Seg–Sex 09:00",
+        unwrapped_description_options("0 9 * * MON-FRI", &compact)
+    );
+    let abbreviated = Options {
+        abbreviated: true,
+        ..compact
+    };
+    assert_eq!(
+        "Dias úteis 09:00–17:00, a cada 15 min",
+        unwrapped_description_options("*/15 9-17 * * 1-5", &abbreviated)
+    );
+    assert_eq!(
+        "Seg, Qua, Sex 09:00",
+        unwrapped_description_options("0 9 * * 1,3,5", &abbreviated)
+    );
+    assert_eq!(
+        "09:00, Fev–Abr, Set",
+        unwrapped_description_options("0 9 * FEB-APR,SEP *", &abbreviated)
+    );
+}
+
+#[test]
+fn test_ordinal_days() {
+    let ordinal = Options {
+        ordinal_days: true,
+        ..Options::options()
+    };
+    assert_eq!(
+        "Às 00:00, no dia 1º e 15º do mês",
+        unwrapped_description_options("0 0 1,15 * *", &ordinal)
+    );
+    assert_eq!(
+        "Às 09:00, entre os dias 1º e 10º do mês",
+        unwrapped_description_options("0 9 1-10 * *", &ordinal)
+    );
+    assert_eq!(
+        "Às 09:00, a cada 10 dias, começando no dia 5º do mês",
+        unwrapped_description_options("0 9 5/10 * *", &ordinal)
+    );
+}
+
+#[test]
+fn test_time_formatting() {
+    let locale = Options {
+        clock: Clock::Locale,
+        ..Options::options()
+    };
+    assert_eq!(
+        "Às 09:00 e 17:00",
+        unwrapped_description_options("0 9,17 * * *", &locale)
+    );
+    assert_eq!(
+        "A cada 5 minutos entre 09:00 e 17:55",
+        unwrapped_description_options("*/5 9-17 * * *", &locale)
+    );
+    let spoken = Options {
+        noon_and_midnight: true,
+        ..Options::options()
+    };
+    assert_eq!(
+        "Às meio-dia",
+        unwrapped_description_options("0 12 * * *", &spoken)
+    );
+}
+
+#[test]
+fn test_plurals() {
+    assert_eq!("Aos 1 segundo após o minuto", unwrapped_description("1 * * * * *"));
+    assert_eq!("Aos 1 e 2 segundos após o minuto", unwrapped_description("1,2 * * * * *"));
+    assert_eq!("Às 09:00, 2 dias antes do último dia do mês", unwrapped_description("0 9 L-2 * ?"));
+}
+
+#[test]
+fn test_localized_names() {
+    let portuguese = Options {
+        name_locale: NameLocale::Current,
+        ..Options::options()
+    };
+    assert_eq!(
+        "Às 09:00, Segunda a Sexta",
+        unwrapped_description_options("0 9 * * SEG-SEX", &portuguese)
+    );
+    assert_eq!(
+        "Às 09:00, somente Sábado, somente em Setembro",
+        unwrapped_description_options("0 9 * set sáb", &portuguese)
+    );
+    assert_eq!(
+        "Às 09:00, somente Terça, somente em Março",
+        unwrapped_description_options("0 9 * MARCO TER", &portuguese)
+    );
+}
@@ -0,0 +1,84 @@
+use cron_descriptor::cronparser::{Options, Verbosity};
+mod test_utils;
+use crate::test_utils::unwrapped_description_options;
+
+#[ctor::ctor]
+fn init() {
+    rust_i18n::set_locale("ru");
+}
+
+fn description(cron_expr: &str) -> String {
+    unwrapped_description_options(cron_expr, &Options::twenty_four_hour())
+}
+
+#[test]
+fn test_every_minute() {
+    assert_eq!("Каждую минуту", description("* * * * *"));
+    assert_eq!("Каждую секунду", description("*/1 * * * * *"));
+}
+
+#[test]
+fn test_plurals() {
+    assert_eq!("Каждые 2 минуты", description("*/2 * * * *"));
+    assert_eq!("Каждые 5 минут", description("*/5 * * * *"));
+    assert_eq!("Каждые 12 минут", description("*/12 * * * *"));
+    assert_eq!("Каждые 21 минуту", description("*/21 * * * *"));
+    assert_eq!("Каждые 22 минуты", description("*/22 * * * *"));
+    assert_eq!("Каждые 3 часа", description("0 */3 * * *"));
+    assert_eq!("Через 1 секунду после начала минуты", description("1 * * * * *"));
+    assert_eq!("Через 1 и 2 секунды после начала минуты", description("1,2 * * * * *"));
+}
+
+#[test]
+fn test_days_before_last_day_of_month() {
+    assert_eq!("В 09:00, за 1 день до последнего дня месяца", description("0 9 L-1 * ?"));
+    assert_eq!("В 09:00, за 3 дня до последнего дня месяца", description("0 9 L-3 * ?"));
+    assert_eq!("В 09:00, за 5 дней до последнего дня месяца", description("0 9 L-5 * ?"));
+}
+
+#[test]
+fn test_day_of_week_cases() {
+    assert_eq!("В 09:00, с понедельника по пятницу", description("0 9 * * MON-FRI"));
+    assert_eq!("В 09:00, со вторника по среду", description("0 9 * * TUE-WED"));
+    assert_eq!("В 09:00, только во вторник, в среду и в пятницу", description("0 9 * * 2,3,5"));
+    assert_eq!("В 09:00, только в воскресенье", description("0 9 * * 0"));
+    assert_eq!("В 09:00, каждые 2 дня недели, начиная со среды", description("0 9 * * 3/2"));
+    assert_eq!("В 09:00, 1 числа месяца, или во вторник", description("0 9 1 * TUE"));
+}
+
+#[test]
+fn test_nth_and_last_day_of_week() {
+    assert_eq!("В 09:00, во второй понедельник месяца", description("0 9 ? * MON#2"));
+    assert_eq!("В 09:00, во вторую среду месяца", description("0 9 ? * WED#2"));
+    assert_eq!("В 09:00, в первое воскресенье месяца", description("0 9 ? * SUN#1"));
+    assert_eq!(
+        "В 09:00, в третью пятницу и в первый понедельник месяца",
+        description("0 9 ? * FRI#3,MON#1")
+    );
+    assert_eq!("В 09:00, в последнюю пятницу месяца", description("0 9 ? * 5L"));
+    assert_eq!("В 09:00, в последнее воскресенье месяца", description("0 9 ? * 0L"));
+}
+
+#[test]
+fn test_month_cases() {
+    assert_eq!("В 09:00, с января по март", description("0 9 * JAN-MAR *"));
+    assert_eq!("В 09:00, только в январе, в марте и в июле", description("0 9 * 1,3,7 *"));
+    assert_eq!("В 09:00, каждые 2 месяца, начиная с марта", description("0 9 * 3/2 *"));
+}
+
+#[test]
+fn test_ordinal_days() {
+    let opts = Options { ordinal_days: true, ..Options::twenty_four_hour() };
+    assert_eq!("В 00:00, 1-го и 15-го числа месяца", unwrapped_description_options("0 0 1,15 * *", &opts));
+    assert_eq!("В 09:00, в 2-ю среду месяца", unwrapped_description_options("0 9 ? * WED#2", &opts));
+}
+
+#[test]
+fn test_compact() {
+    let opts = Options {
+        verbosity: Verbosity::Compact,
+        abbreviated: true,
+        ..Options::twenty_four_hour()
+    };
+    assert_eq!("Будни 09:00–17:00, каждые 15 мин", unwrapped_description_options("*/15 9-17 * * 1-5", &opts));
+}
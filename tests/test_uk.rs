@@ -0,0 +1,63 @@
+use cron_descriptor::cronparser::{Options, Verbosity};
+mod test_utils;
+use crate::test_utils::unwrapped_description_options;
+
+#[ctor::ctor]
+fn init() {
+    rust_i18n::set_locale("uk");
+}
+
+fn description(cron_expr: &str) -> String {
+    unwrapped_description_options(cron_expr, &Options::twenty_four_hour())
+}
+
+#[test]
+fn test_every_minute() {
+    assert_eq!("Щохвилини", description("* * * * *"));
+    assert_eq!("Щосекунди", description("*/1 * * * * *"));
+}
+
+#[test]
+fn test_plurals() {
+    assert_eq!("Кожні 2 хвилини", description("*/2 * * * *"));
+    assert_eq!("Кожні 5 хвилин", description("*/5 * * * *"));
+    assert_eq!("Кожні 12 хвилин", description("*/12 * * * *"));
+    assert_eq!("Кожні 21 хвилину", description("*/21 * * * *"));
+    assert_eq!("Кожні 3 години", description("0 */3 * * *"));
+    assert_eq!("Через 1 і 2 секунди після початку хвилини", description("1,2 * * * * *"));
+}
+
+#[test]
+fn test_days_before_last_day_of_month() {
+    assert_eq!("О 09:00, за 1 день до останнього дня місяця", description("0 9 L-1 * ?"));
+    assert_eq!("О 09:00, за 3 дні до останнього дня місяця", description("0 9 L-3 * ?"));
+    assert_eq!("О 09:00, за 5 днів до останнього дня місяця", description("0 9 L-5 * ?"));
+}
+
+#[test]
+fn test_day_of_week_cases() {
+    assert_eq!("О 09:00, з понеділка по пʼятницю", description("0 9 * * MON-FRI"));
+    assert_eq!("О 09:00, з вівторка по середу", description("0 9 * * TUE-WED"));
+    assert_eq!("О 09:00, лише у вівторок, у середу і у пʼятницю", description("0 9 * * 2,3,5"));
+    assert_eq!("О 09:00, кожні 2 дні тижня, починаючи із середи", description("0 9 * * 3/2"));
+}
+
+#[test]
+fn test_nth_and_last_day_of_week() {
+    assert_eq!("О 09:00, у другий понеділок місяця", description("0 9 ? * MON#2"));
+    assert_eq!("О 09:00, у другу середу місяця", description("0 9 ? * WED#2"));
+    assert_eq!("О 09:00, в останню пʼятницю місяця", description("0 9 ? * 5L"));
+}
+
+#[test]
+fn test_month_cases() {
+    assert_eq!("О 09:00, з січня по березень", description("0 9 * JAN-MAR *"));
+    assert_eq!("О 09:00, лише у січні, у березні і у липні", description("0 9 * 1,3,7 *"));
+    assert_eq!("О 09:00, кожні 2 місяці, починаючи з березня", description("0 9 * 3/2 *"));
+}
+
+#[test]
+fn test_compact() {
+    let opts = Options { verbosity: Verbosity::Compact, ..Options::twenty_four_hour() };
+    assert_eq!("09:00, січ–бер, лип, 2025–2027", unwrapped_description_options("0 9 * JAN-MAR,JUL * 2025-2027", &opts));
+}
@@ -1,3 +1,5 @@
+// Shared by every locale's tests, each of which uses only some of the helpers.
+#![allow(dead_code)]
 
 use cron_descriptor::cronparser::cron_expression_descriptor;
 use cron_descriptor::cronparser::Options;
@@ -9,3 +11,94 @@ pub fn unwrapped_description(cron_expr: &str) -> String {
 pub fn unwrapped_description_options(cron_expr: &str, opts: &Options) -> String {
     cron_expression_descriptor::get_description_cron_options(cron_expr, opts).unwrap()
 }
+
+pub const MONTH_NAMES: [(&str, &str); 12] = [
+    ("JAN", "January"),
+    ("FEB", "February"),
+    ("MAR", "March"),
+    ("APR", "April"),
+    ("MAY", "May"),
+    ("JUN", "June"),
+    ("JUL", "July"),
+    ("AUG", "August"),
+    ("SEP", "September"),
+    ("OCT", "October"),
+    ("NOV", "November"),
+    ("DEC", "December"),
+];
+
+pub const DAY_OF_WEEK_NAMES: [(&str, &str); 7] = [
+    ("SUN", "Sunday"),
+    ("MON", "Monday"),
+    ("TUE", "Tuesday"),
+    ("WED", "Wednesday"),
+    ("THU", "Thursday"),
+    ("FRI", "Friday"),
+    ("SAT", "Saturday"),
+];
+
+// Every spelling of a name: upper and lower case, abbreviated and full.
+pub fn name_variants(name: &(&str, &str)) -> Vec<String> {
+    vec![
+        name.0.to_string(),
+        name.0.to_lowercase(),
+        name.1.to_string(),
+        name.1.to_uppercase(),
+    ]
+}
+
+// Checks that every month name is described like its number, alone and in a range, a list
+// and a step.
+pub fn assert_month_names_like_numbers() {
+    for (i, name) in MONTH_NAMES.iter().enumerate() {
+        let n = i + 1;
+        for variant in name_variants(name) {
+            for (numeric, named) in [
+                (format!("{}", n), variant.clone()),
+                (format!("1-{}", n), format!("JAN-{}", variant)),
+                (format!("{},12", n), format!("{},dec", variant)),
+                (format!("{}/2", n), format!("{}/2", variant)),
+            ] {
+                assert_eq!(
+                    unwrapped_description(&format!("0 9 * {} *", numeric)),
+                    unwrapped_description(&format!("0 9 * {} *", named)),
+                    "{}",
+                    named
+                );
+            }
+        }
+    }
+}
+
+// Checks that every day name is described like its number, alone and in a range, a list
+// and a step, whether days of the week are zero based or not.
+pub fn assert_day_of_week_names_like_numbers() {
+    let non_zero_based = Options {
+        zero_based_day_of_week: false,
+        ..Options::options()
+    };
+    for (i, name) in DAY_OF_WEEK_NAMES.iter().enumerate() {
+        for variant in name_variants(name) {
+            for (numeric, named) in [
+                (format!("{}", i), variant.clone()),
+                (format!("{}-6", i), format!("{}-SAT", variant)),
+                (format!("0,{}", i), format!("sun,{}", variant)),
+                (format!("{}/2", i), format!("{}/2", variant)),
+                (format!("{}#2", i), format!("{}#2", variant)),
+            ] {
+                assert_eq!(
+                    unwrapped_description(&format!("0 9 ? * {}", numeric)),
+                    unwrapped_description(&format!("0 9 ? * {}", named)),
+                    "{}",
+                    named
+                );
+            }
+            assert_eq!(
+                unwrapped_description_options(&format!("0 9 ? * {}", i + 1), &non_zero_based),
+                unwrapped_description_options(&format!("0 9 ? * {}", variant), &non_zero_based),
+                "{}",
+                variant
+            );
+        }
+    }
+}
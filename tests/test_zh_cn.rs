@@ -0,0 +1,31 @@
+use cron_descriptor::cronparser::Options;
+mod test_utils;
+use crate::test_utils::unwrapped_description_options;
+
+#[ctor::ctor]
+fn init() {
+    rust_i18n::set_locale("zh-CN");
+}
+
+fn description(cron_expr: &str) -> String {
+    unwrapped_description_options(cron_expr, &Options::twenty_four_hour())
+}
+
+#[test]
+fn test_intervals() {
+    assert_eq!("每分钟", description("* * * * *"));
+    assert_eq!("每隔5分钟", description("*/5 * * * *"));
+    assert_eq!("每分钟的第1和2秒", description("1,2 * * * * *"));
+    assert_eq!("09:00至17:55每隔5分钟", description("*/5 9-17 * * *"));
+    assert_eq!("22:00至次日02:00", description("0 22-2 * * *"));
+}
+
+#[test]
+fn test_days_before_time() {
+    assert_eq!("星期一至星期五，09:00", description("0 9 * * MON-FRI"));
+    assert_eq!("仅星期二、星期三和星期五，09:00", description("0 9 * * 2,3,5"));
+    assert_eq!("每月第二个星期三，09:00", description("0 9 ? * WED#2"));
+    assert_eq!("每月最后一天的前3天，09:00", description("0 9 L-3 * ?"));
+    assert_eq!("每隔2个月，从3月开始，09:00", description("0 9 * 3/2 *"));
+    assert_eq!("仅2025年，09:00", description("0 9 * * * 2025"));
+}
@@ -0,0 +1,29 @@
+use cron_descriptor::cronparser::Options;
+mod test_utils;
+use crate::test_utils::unwrapped_description_options;
+
+#[ctor::ctor]
+fn init() {
+    rust_i18n::set_locale("zh-TW");
+}
+
+fn description(cron_expr: &str) -> String {
+    unwrapped_description_options(cron_expr, &Options::twenty_four_hour())
+}
+
+#[test]
+fn test_intervals() {
+    assert_eq!("每分鐘", description("* * * * *"));
+    assert_eq!("每隔5分鐘", description("*/5 * * * *"));
+    assert_eq!("每小時的第05和10分鐘", description("5,10 * * * *"));
+    assert_eq!("22:00至隔天02:00", description("0 22-2 * * *"));
+}
+
+#[test]
+fn test_days_before_time() {
+    assert_eq!("星期一至星期五，09:00", description("0 9 * * MON-FRI"));
+    assert_eq!("僅星期二、星期三和星期五，09:00", description("0 9 * * 2,3,5"));
+    assert_eq!("每月最後一個星期五，09:00", description("0 9 ? * 5L"));
+    assert_eq!("每月1日至10日，09:00", description("0 9 1-10 * *"));
+    assert_eq!("僅1月、3月和7月，09:00", description("0 9 * 1,3,7 *"));
+}